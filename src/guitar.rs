@@ -1,16 +1,34 @@
-use minstrel::Note;
-use std::{fmt, str::FromStr};
+use crate::Luthier;
+use minstrel::{Key, Note};
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt,
+    str::FromStr,
+};
+
+/// The number of consecutive frets that a fretting hand can comfortably cover.
+const HAND_SPAN: usize = 4;
 
 /// A guitar with any number of strings.
 #[derive(Debug)]
 pub struct Guitar {
     pub(crate) num_frets: usize,
+    pub(crate) capo: usize,
     pub(crate) strings: Vec<GuitarString>,
 }
 
 impl Guitar {
+    /// Returns the number of frets on the guitar.
+    pub fn num_frets(&self) -> usize {
+        self.num_frets
+    }
+
     /// Returns the fretboard locations of the given note.
     ///
+    /// Locations are grouped by string number, from the highest string to
+    /// the lowest. See `all_positions()` for locations ordered along the neck,
+    /// and `all_positions_pitch_class()` for locations in any octave.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -44,103 +62,3122 @@ impl Guitar {
 
         locations
     }
-}
 
-/// A single guitar string, represented as the note values of
-/// each of its frets.
-#[derive(Debug)]
-pub(crate) struct GuitarString {
-    frets: Vec<Note>,
-}
+    /// Returns the fretboard locations of the given note that lie between the
+    /// frets `low` and `high` (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(20)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let locations = guitar.locations_in_range(Note::from_str("E3").unwrap(), 0, 7);
+    /// assert_eq!(
+    ///     locations,
+    ///     vec![FretboardLocation::new(4, 2), FretboardLocation::new(5, 7)]
+    /// );
+    /// ```
+    pub fn locations_in_range(
+        &self,
+        note: Note,
+        low: usize,
+        high: usize,
+    ) -> Vec<FretboardLocation> {
+        self.locations(note)
+            .into_iter()
+            .filter(|loc| (low..=high).contains(&loc.fret_number))
+            .collect()
+    }
 
-impl GuitarString {
-    /// Creates a new `GuitarString`.
+    /// Returns every fretboard location of the given note, like `locations()`,
+    /// but sorted by fret number and then by string number (see
+    /// `FretboardLocation`'s ordering) rather than grouped by string.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use gitar::GuitarString;
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(20)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     guitar.all_positions(Note::from_str("B3").unwrap()),
+    ///     vec![
+    ///         FretboardLocation::new(2, 0),
+    ///         FretboardLocation::new(3, 4),
+    ///         FretboardLocation::new(4, 9),
+    ///         FretboardLocation::new(5, 14),
+    ///         FretboardLocation::new(6, 19),
+    ///     ]
+    /// );
+    /// ```
+    pub fn all_positions(&self, note: Note) -> Vec<FretboardLocation> {
+        let mut positions = self.locations(note);
+        positions.sort();
+        positions.dedup();
+        positions
+    }
+
+    /// Returns every fretboard location of the given note in any octave,
+    /// sorted in the same way as `all_positions()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
     /// use minstrel::Note;
     /// use std::str::FromStr;
     ///
-    /// let e_string = GuitarString::new(Note::from_str("E2"), 20);
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     guitar.all_positions_pitch_class(Note::from_str("B").unwrap()),
+    ///     vec![
+    ///         FretboardLocation::new(2, 0),
+    ///         FretboardLocation::new(5, 2),
+    ///         FretboardLocation::new(3, 4),
+    ///         FretboardLocation::new(1, 7),
+    ///         FretboardLocation::new(6, 7),
+    ///         FretboardLocation::new(4, 9),
+    ///         FretboardLocation::new(2, 12),
+    ///     ]
+    /// );
     /// ```
-    pub(crate) fn new(open_note: Note, num_frets: usize) -> Self {
-        Self {
-            // 1 is added to `num_frets` to include the open string
-            frets: open_note.into_iter().take(num_frets + 1).collect(),
+    pub fn all_positions_pitch_class(&self, note: Note) -> Vec<FretboardLocation> {
+        let pitch_class = note.disregard_octave();
+
+        let mut positions = Vec::new();
+        for (string_idx, string) in self.strings.iter().enumerate() {
+            for (fret_idx, fret) in string.frets.iter().enumerate() {
+                if fret.disregard_octave() == pitch_class {
+                    positions.push(FretboardLocation::new(string_idx + 1, fret_idx));
+                }
+            }
         }
+
+        positions.sort();
+        positions.dedup();
+        positions
     }
-}
 
-/// Standard, six-string guitar tuning.
-pub fn standard_tuning() -> Vec<Note> {
-    vec![
-        Note::from_str("E2").unwrap(),
-        Note::from_str("A2").unwrap(),
-        Note::from_str("D3").unwrap(),
-        Note::from_str("G3").unwrap(),
-        Note::from_str("B3").unwrap(),
-        Note::from_str("E4").unwrap(),
-    ]
-}
+    /// Returns every fretboard location within the hand position that starts
+    /// at `anchor_fret` and covers `span` consecutive frets, regardless of
+    /// note.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let positions = guitar.positions_within_span(5, 4);
+    /// assert_eq!(positions.len(), 24);
+    /// assert!(positions.contains(&FretboardLocation::new(1, 5)));
+    /// assert!(positions.contains(&FretboardLocation::new(6, 8)));
+    /// assert!(!positions.contains(&FretboardLocation::new(6, 9)));
+    ///
+    /// // A span that runs off the end of the neck stops at the last fret
+    /// assert_eq!(guitar.positions_within_span(3, usize::MAX).len(), 6 * 19);
+    /// ```
+    pub fn positions_within_span(&self, anchor_fret: usize, span: usize) -> Vec<FretboardLocation> {
+        let highest_fret = anchor_fret.saturating_add(span).min(self.num_frets + 1);
 
-/// A diagram of a `Guitar` fretboard, depicting the locations of certain notes.
-pub struct FretboardDiagram<'g> {
-    guitar: &'g Guitar,
-    locations: Vec<FretboardLocation>,
-}
+        let mut positions = Vec::new();
+        for string_num in 1..=self.strings.len() {
+            for fret_idx in anchor_fret..highest_fret {
+                positions.push(FretboardLocation::new(string_num, fret_idx));
+            }
+        }
 
-impl<'g> FretboardDiagram<'g> {
-    /// Creates a new `FretboardDiagram` based on the given `guitar` and
-    /// fretboard `locations`.
-    pub fn new(guitar: &'g Guitar, locations: Vec<FretboardLocation>) -> Self {
-        Self { guitar, locations }
+        positions
     }
-}
 
-impl<'g> fmt::Display for FretboardDiagram<'g> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // The values can be unwrapped because the case in which there are no fretboard
-        // locations has already been handled (see the above `match` statement)
-        let fret_numbers = self.locations.iter().map(|loc| loc.fret_number);
-        let lowest_fret_num = fret_numbers.clone().min().unwrap();
-        let highest_fret_num = fret_numbers.max().unwrap();
+    /// Returns the fretboard locations of each of the given `notes`, grouped
+    /// by note in the order that they were given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let b2 = Note::from_str("B2").unwrap();
+    /// let e2 = Note::from_str("E2").unwrap();
+    /// assert_eq!(
+    ///     guitar.locations_grouped(&[b2, e2]),
+    ///     vec![
+    ///         (b2, vec![FretboardLocation::new(5, 2), FretboardLocation::new(6, 7)]),
+    ///         (e2, vec![FretboardLocation::new(6, 0)]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn locations_grouped(&self, notes: &[Note]) -> Vec<(Note, Vec<FretboardLocation>)> {
+        notes
+            .iter()
+            .map(|note| (*note, self.locations(*note)))
+            .collect()
+    }
 
-        // Draws a fretboard diagram showing all of the note locations
-        for fret_idx in lowest_fret_num..=highest_fret_num {
-            for string_num in (1..=self.guitar.strings.len()).rev() {
-                let current_loc = FretboardLocation::new(string_num, fret_idx);
-                if self.locations.contains(&current_loc) {
-                    f.write_str("∗")?;
-                } else if fret_idx == 0 {
-                    f.write_str("-")?;
-                } else {
-                    f.write_str("│")?;
+    /// Returns the note at the given fret of the string with the given
+    /// `string_number`, or `None` if there is no such location on the guitar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// assert_eq!(guitar.note_at(6, 0), Some(Note::from_str("E2").unwrap()));
+    /// assert_eq!(guitar.note_at(3, 2), Some(Note::from_str("A3").unwrap()));
+    /// assert_eq!(guitar.note_at(7, 0), None);
+    /// assert_eq!(guitar.note_at(1, 22), None);
+    /// ```
+    pub fn note_at(&self, string_number: usize, fret_number: usize) -> Option<Note> {
+        self.strings
+            .get(string_number.checked_sub(1)?)?
+            .note_at_fret(fret_number)
+    }
+
+    /// Returns the note that sounds when the given fret of the string with the
+    /// given `string_number` is played, or `None` if there is no such
+    /// location on the guitar.
+    ///
+    /// Unlike `note_at`, which counts frets from the capo (if there is one),
+    /// the `fret_number` is counted from the nut, as marked on the neck. Frets
+    /// behind the capo do not sound, and so also return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .add_capo(3)
+    ///     .build();
+    ///
+    /// // The capo itself acts as the nut
+    /// assert_eq!(guitar.sounding_note_at(6, 3), Some(Note::from_str("G2").unwrap()));
+    /// assert_eq!(guitar.sounding_note_at(6, 5), Some(Note::from_str("A2").unwrap()));
+    /// assert_eq!(guitar.note_at(6, 5), Some(Note::from_str("C3").unwrap()));
+    /// assert_eq!(guitar.sounding_note_at(6, 2), None);
+    /// ```
+    pub fn sounding_note_at(&self, string_number: usize, fret_number: usize) -> Option<Note> {
+        self.note_at(string_number, fret_number.checked_sub(self.capo)?)
+    }
+
+    /// Returns an iterator over the open notes of the guitar's strings,
+    /// ordered from the lowest string to the highest (i.e. in the same order
+    /// as the tuning given to `Luthier::string`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let luthier = gitar::Luthier::new(20).string(gitar::standard_tuning());
+    /// let guitar = luthier.build();
+    ///
+    /// let open_notes: Vec<_> = guitar.open_notes().collect();
+    /// assert_eq!(open_notes, gitar::standard_tuning());
+    /// ```
+    pub fn open_notes(&self) -> impl Iterator<Item = Note> + '_ {
+        // The strings are stored from highest to lowest, so they are reversed
+        self.strings.iter().rev().map(|string| string.frets[0])
+    }
+
+    /// Returns every note on the guitar's fretboard, indexed by
+    /// `[string][fret]`.
+    ///
+    /// Strings are ordered by string number, so index 0 is the first
+    /// (highest) string; fret index 0 is the open string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let grid = guitar.fretboard_grid();
+    /// assert_eq!(grid.len(), 6);
+    /// assert_eq!(grid[0].len(), 22);
+    /// assert_eq!(grid[0][0], Note::from_str("E4").unwrap());
+    /// assert_eq!(grid[5][5], Note::from_str("A2").unwrap());
+    /// assert_eq!(grid[2][4], Note::from_str("B3").unwrap());
+    /// ```
+    pub fn fretboard_grid(&self) -> Vec<Vec<Note>> {
+        self.strings
+            .iter()
+            .map(|string| string.frets.clone())
+            .collect()
+    }
+
+    /// Returns the location of every natural harmonic on the fretboard (see
+    /// `GuitarString::harmonic_at()`), along with the note that it sounds.
+    ///
+    /// Harmonics are ordered by string number, and then by fret number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let harmonics = guitar.harmonic_locations();
+    /// // Frets 3, 4, 5, 7, 9 and 12 on each of the six strings
+    /// assert_eq!(harmonics.len(), 36);
+    ///
+    /// // The twelfth-fret harmonic sounds an octave above the open string
+    /// for (string_idx, open_note) in guitar.open_notes().enumerate() {
+    ///     let twelfth_fret = FretboardLocation::new(6 - string_idx, 12);
+    ///     assert!(harmonics.contains(&(twelfth_fret, open_note + 12)));
+    /// }
+    /// ```
+    pub fn harmonic_locations(&self) -> Vec<(FretboardLocation, Note)> {
+        let mut harmonics = Vec::new();
+        for (string_idx, string) in self.strings.iter().enumerate() {
+            for fret_idx in 0..=self.num_frets {
+                if let Some(harmonic) = string.harmonic_at(fret_idx) {
+                    harmonics.push((FretboardLocation::new(string_idx + 1, fret_idx), harmonic));
                 }
             }
+        }
+
+        harmonics
+    }
+
+    /// Returns, for each pair of adjacent strings, the number of the lower
+    /// string and the fret on it that plays the open note of the string above
+    /// it. This is the basis of tuning a guitar by unisons.
+    ///
+    /// Pairs are ordered from the lowest string to the highest. A pair is left
+    /// out if the open note of the higher string cannot be played on the
+    /// lower one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     guitar.unison_frets(),
+    ///     vec![(6, 5), (5, 5), (4, 5), (3, 4), (2, 5)]
+    /// );
+    /// ```
+    pub fn unison_frets(&self) -> Vec<(usize, usize)> {
+        let mut unisons = Vec::new();
+        for string_idx in (1..self.strings.len()).rev() {
+            let lower_string = &self.strings[string_idx];
+            let higher_open_note = self.strings[string_idx - 1].frets[0];
 
-            writeln!(f, " {}", fret_idx)?;
+            if let Some(fret_idx) = lower_string
+                .frets
+                .iter()
+                .position(|fret| *fret == higher_open_note)
+            {
+                unisons.push((string_idx + 1, fret_idx));
+            }
         }
 
-        Ok(())
+        unisons
     }
-}
 
-/// A location on a fretboard.
-///
-/// A `fret_number` of 0 indicates an open string.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct FretboardLocation {
-    string_number: usize,
-    fret_number: usize,
-}
+    /// Returns the lowest note that can be played on the guitar, or `None`
+    /// if it has no strings.
+    ///
+    /// Every string and fret is considered, so reentrant tunings (where the
+    /// lowest string is not the lowest sounding one) are handled correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    /// assert_eq!(guitar.lowest_note(), Some(Note::from_str("E2").unwrap()));
+    ///
+    /// // A ukulele's G string is tuned above its C string
+    /// let ukulele = gitar::Luthier::new(12)
+    ///     .string(vec![
+    ///         Note::from_str("G4").unwrap(),
+    ///         Note::from_str("C4").unwrap(),
+    ///         Note::from_str("E4").unwrap(),
+    ///         Note::from_str("A4").unwrap(),
+    ///     ])
+    ///     .build();
+    /// assert_eq!(ukulele.lowest_note(), Some(Note::from_str("C4").unwrap()));
+    /// ```
+    pub fn lowest_note(&self) -> Option<Note> {
+        self.strings
+            .iter()
+            .flat_map(|string| string.frets.iter().copied())
+            .min_by_key(|note| note.value)
+    }
 
-impl FretboardLocation {
-    fn new(string_number: usize, fret_number: usize) -> Self {
-        Self {
-            string_number,
-            fret_number,
+    /// Returns the highest note that can be played on the guitar, or `None`
+    /// if it has no strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    /// assert_eq!(guitar.highest_note(), Some(Note::from_str("Db6").unwrap()));
+    ///
+    /// let ukulele = gitar::Luthier::new(12)
+    ///     .string(vec![
+    ///         Note::from_str("G4").unwrap(),
+    ///         Note::from_str("C4").unwrap(),
+    ///         Note::from_str("E4").unwrap(),
+    ///         Note::from_str("A4").unwrap(),
+    ///     ])
+    ///     .build();
+    /// assert_eq!(ukulele.highest_note(), Some(Note::from_str("A5").unwrap()));
+    /// ```
+    pub fn highest_note(&self) -> Option<Note> {
+        self.strings
+            .iter()
+            .flat_map(|string| string.frets.iter().copied())
+            .max_by_key(|note| note.value)
+    }
+
+    /// Returns the number of distinct pitch classes (of the twelve) that can
+    /// be played on the guitar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    /// assert_eq!(guitar.pitch_class_coverage(), 12);
+    ///
+    /// // A single string with only two frets covers three pitch classes
+    /// let short_guitar = gitar::Luthier::new(2)
+    ///     .string(vec![Note::from_str("E2").unwrap()])
+    ///     .build();
+    /// assert_eq!(short_guitar.pitch_class_coverage(), 3);
+    /// ```
+    pub fn pitch_class_coverage(&self) -> usize {
+        let mut pitch_classes = Vec::new();
+        for string in &self.strings {
+            for fret in &string.frets {
+                let pitch_class = fret.disregard_octave();
+                if !pitch_classes.contains(&pitch_class) {
+                    pitch_classes.push(pitch_class);
+                }
+            }
+        }
+
+        pitch_classes.len()
+    }
+
+    /// Returns the number of semitones between the lowest and highest notes
+    /// that can be played on the guitar. A guitar with no strings has a range
+    /// of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // E2 to Db6
+    /// assert_eq!(guitar.range_in_semitones(), 45);
+    /// ```
+    pub fn range_in_semitones(&self) -> usize {
+        match (self.lowest_note(), self.highest_note()) {
+            (Some(lowest), Some(highest)) => highest - lowest,
+            _ => 0,
         }
     }
+
+    /// Returns the pairs of fretboard locations at which the notes `a` and `b`
+    /// can be played together as a dyad (a two-note shape).
+    ///
+    /// Each pair is made up of a location of `a` followed by a location of
+    /// `b`. The two locations are always on different strings, and are no more
+    /// than three frets apart, so that both fit within a four-fret hand
+    /// position (the same reach used by `voicing_near_fret`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // A perfect fourth
+    /// let dyads = guitar.dyad_locations(
+    ///     Note::from_str("A2").unwrap(),
+    ///     Note::from_str("D3").unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     dyads,
+    ///     vec![
+    ///         (FretboardLocation::new(5, 0), FretboardLocation::new(4, 0)),
+    ///         (FretboardLocation::new(6, 5), FretboardLocation::new(5, 5)),
+    ///     ]
+    /// );
+    ///
+    /// // A major sixth is out of reach as a four-fret stretch across two
+    /// // strings
+    /// let dyads = guitar.dyad_locations(
+    ///     Note::from_str("A2").unwrap(),
+    ///     Note::from_str("Gb3").unwrap(),
+    /// );
+    /// assert!(!dyads.contains(&(FretboardLocation::new(6, 5), FretboardLocation::new(5, 9))));
+    /// ```
+    pub fn dyad_locations(&self, a: Note, b: Note) -> Vec<(FretboardLocation, FretboardLocation)> {
+        self.reachable_pairs(a, b, HAND_SPAN - 1)
+    }
+
+    /// Returns the pairs of fretboard locations at which the notes `a` and `b`
+    /// can be played simultaneously, i.e. pairs of locations on different
+    /// strings that are no more than `max_span` frets apart.
+    ///
+    /// Each pair is made up of a location of `a` followed by a location of
+    /// `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // A major third, which spans adjacent strings
+    /// let pairs = guitar.reachable_pairs(
+    ///     Note::from_str("G3").unwrap(),
+    ///     Note::from_str("B3").unwrap(),
+    ///     1,
+    /// );
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         (FretboardLocation::new(3, 0), FretboardLocation::new(2, 0)),
+    ///         (FretboardLocation::new(4, 5), FretboardLocation::new(3, 4)),
+    ///         (FretboardLocation::new(5, 10), FretboardLocation::new(4, 9)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn reachable_pairs(
+        &self,
+        a: Note,
+        b: Note,
+        max_span: usize,
+    ) -> Vec<(FretboardLocation, FretboardLocation)> {
+        let b_locations = self.locations(b);
+        let mut pairs = Vec::new();
+        for a_loc in self.locations(a) {
+            for b_loc in &b_locations {
+                let span = a_loc.fret_number.abs_diff(b_loc.fret_number);
+
+                if a_loc.string_number != b_loc.string_number && span <= max_span {
+                    pairs.push((a_loc, *b_loc));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Returns the pairs of fretboard locations that play the given `note`
+    /// (in any octave) together with the note an octave above it, within the
+    /// same reach as `dyad_locations`.
+    ///
+    /// Each pair is made up of the lower location followed by the higher one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let pairs = guitar.octave_pairs(Note::from_str("E").unwrap());
+    /// // E2 and E3, two strings and two frets apart
+    /// assert!(pairs.contains(&(FretboardLocation::new(6, 0), FretboardLocation::new(4, 2))));
+    /// // E3 and E4, in the same shape further up the neck
+    /// assert!(pairs.contains(&(FretboardLocation::new(5, 7), FretboardLocation::new(3, 9))));
+    /// ```
+    pub fn octave_pairs(&self, note: Note) -> Vec<(FretboardLocation, FretboardLocation)> {
+        let highest_note = match self.highest_note() {
+            Some(highest_note) => highest_note,
+            None => return Vec::new(),
+        };
+
+        note.disregard_octave()
+            .into_iter()
+            .step_by(12)
+            .take_while(|lower| lower.value + 12 <= highest_note.value)
+            .flat_map(|lower| self.dyad_locations(lower, lower + 12))
+            .collect()
+    }
+
+    /// Returns the fret at which each of the melody's `notes` can be played on
+    /// the string with the given `string_number`, or `None` if the string
+    /// does not exist or any of the notes is out of its range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let melody = [
+    ///     Note::from_str("E4").unwrap(),
+    ///     Note::from_str("G4").unwrap(),
+    ///     Note::from_str("A4").unwrap(),
+    /// ];
+    /// assert_eq!(guitar.melody_on_string(&melody, 1), Some(vec![0, 3, 5]));
+    ///
+    /// // E3 is lower than the high E string's open note
+    /// assert_eq!(guitar.melody_on_string(&[Note::from_str("E3").unwrap()], 1), None);
+    /// ```
+    pub fn melody_on_string(&self, notes: &[Note], string_number: usize) -> Option<Vec<usize>> {
+        let string = self.strings.get(string_number.checked_sub(1)?)?;
+        notes
+            .iter()
+            .map(|note| string.frets.iter().position(|fret| fret == note))
+            .collect()
+    }
+
+    /// Returns the locations of the chord's `notes` between the frets `low`
+    /// and `high` (inclusive), ordered by ascending pitch so that they can be
+    /// played as an arpeggio.
+    ///
+    /// Octaves are disregarded when matching chord tones. If a pitch can be
+    /// played in more than one place, only the location on the lower string
+    /// is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // C major
+    /// let notes = [
+    ///     Note::from_str("C").unwrap(),
+    ///     Note::from_str("E").unwrap(),
+    ///     Note::from_str("G").unwrap(),
+    /// ];
+    /// assert_eq!(
+    ///     guitar.arpeggio(&notes, 0, 5),
+    ///     vec![
+    ///         FretboardLocation::new(6, 0),
+    ///         FretboardLocation::new(6, 3),
+    ///         FretboardLocation::new(5, 3),
+    ///         FretboardLocation::new(4, 2),
+    ///         FretboardLocation::new(4, 5),
+    ///         FretboardLocation::new(3, 5),
+    ///         FretboardLocation::new(2, 5),
+    ///         FretboardLocation::new(1, 3),
+    ///     ]
+    /// );
+    /// ```
+    pub fn arpeggio(&self, notes: &[Note], low: usize, high: usize) -> Vec<FretboardLocation> {
+        let chord_tones: Vec<_> = notes.iter().map(|note| note.disregard_octave()).collect();
+
+        let mut arpeggio = Vec::new();
+        for (string_idx, string) in self.strings.iter().enumerate() {
+            for fret_idx in low..=high.min(self.num_frets) {
+                let note = string.frets[fret_idx];
+                if chord_tones.contains(&note.disregard_octave()) {
+                    arpeggio.push((note, FretboardLocation::new(string_idx + 1, fret_idx)));
+                }
+            }
+        }
+
+        // Orders the locations by pitch, with those on lower strings first so
+        // that they are the ones kept when duplicate pitches are removed
+        arpeggio.sort_by_key(|(note, loc)| (note.value, Reverse(loc.string_number)));
+        arpeggio.dedup_by_key(|(note, _)| note.value);
+        arpeggio.into_iter().map(|(_, loc)| loc).collect()
+    }
+
+    /// Returns a diagram of the whole neck with every occurence of the given
+    /// chord `notes` (in any octave) marked, showing where the chord's tones
+    /// lie rather than any single voicing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(vec![Note::from_str("C3").unwrap()])
+    ///     .build();
+    ///
+    /// // C major seventh
+    /// let notes = [
+    ///     Note::from_str("C").unwrap(),
+    ///     Note::from_str("E").unwrap(),
+    ///     Note::from_str("G").unwrap(),
+    ///     Note::from_str("B").unwrap(),
+    /// ];
+    /// assert_eq!(
+    ///     guitar.chord_map(&notes).to_string(),
+    ///     "∗‖───│───│───│─∗─│───│───│─∗─│───│───│───│─∗─│─∗─│\n\
+    ///      0  1   2   3   4   5   6   7   8   9  10  11  12\n"
+    /// );
+    /// ```
+    pub fn chord_map(&self, notes: &[Note]) -> NeckDiagram<'_> {
+        let chord_tones: Vec<_> = notes.iter().map(|note| note.disregard_octave()).collect();
+
+        let mut locations = Vec::new();
+        for (string_idx, string) in self.strings.iter().enumerate() {
+            for (fret_idx, note) in string.frets.iter().enumerate() {
+                if chord_tones.contains(&note.disregard_octave()) {
+                    locations.push(FretboardLocation::new(string_idx + 1, fret_idx));
+                }
+            }
+        }
+
+        NeckDiagram::new(self, locations)
+    }
+
+    /// Returns a diagram of the whole neck with every note of the given `key`
+    /// marked, and the tones of the chord made up of the given `notes`
+    /// highlighted (see `NeckDiagram::highlight()`). This is useful for
+    /// improvising over a chord.
+    ///
+    /// Octaves are disregarded when comparing notes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::{Key, Mode, Note};
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(vec![Note::from_str("C3").unwrap()])
+    ///     .build();
+    ///
+    /// // C major over an A minor chord
+    /// let c_major = Key::new(Note::new(0), Mode::Ionian);
+    /// let a_minor = [
+    ///     Note::from_str("A").unwrap(),
+    ///     Note::from_str("C").unwrap(),
+    ///     Note::from_str("E").unwrap(),
+    /// ];
+    /// assert_eq!(
+    ///     guitar.scale_with_chord_diagram(c_major, &a_minor).to_string(),
+    ///     "O‖───│─∗─│───│─O─│─∗─│───│─∗─│───│─O─│───│─∗─│─O─│\n\
+    ///      0  1   2   3   4   5   6   7   8   9  10  11  12\n"
+    /// );
+    /// ```
+    pub fn scale_with_chord_diagram(&self, key: Key, notes: &[Note]) -> NeckDiagram<'_> {
+        let scale = key.notes_disregarding_octave();
+        let chord_tones: Vec<_> = notes.iter().map(|note| note.disregard_octave()).collect();
+
+        let mut scale_locations = Vec::new();
+        let mut chord_locations = Vec::new();
+        for (string_idx, string) in self.strings.iter().enumerate() {
+            for (fret_idx, note) in string.frets.iter().enumerate() {
+                let loc = FretboardLocation::new(string_idx + 1, fret_idx);
+                let pitch_class = note.disregard_octave();
+                if chord_tones.contains(&pitch_class) {
+                    chord_locations.push(loc);
+                } else if scale.contains(&pitch_class) {
+                    scale_locations.push(loc);
+                }
+            }
+        }
+
+        NeckDiagram::new(self, scale_locations).highlight(chord_locations)
+    }
+
+    /// Returns the numbers of the strings whose open note is one of the given
+    /// chord `notes`, i.e. the strings that can ring open in the chord.
+    ///
+    /// Octaves are disregarded when comparing notes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // G major
+    /// let notes = [
+    ///     Note::from_str("G").unwrap(),
+    ///     Note::from_str("B").unwrap(),
+    ///     Note::from_str("D").unwrap(),
+    /// ];
+    /// assert_eq!(guitar.open_chord_tones(&notes), vec![2, 3, 4]);
+    /// ```
+    pub fn open_chord_tones(&self, notes: &[Note]) -> Vec<usize> {
+        let notes: Vec<_> = notes.iter().map(|note| note.disregard_octave()).collect();
+        self.strings
+            .iter()
+            .enumerate()
+            .filter(|(_, string)| notes.contains(&string.frets[0].disregard_octave()))
+            .map(|(string_idx, _)| string_idx + 1)
+            .collect()
+    }
+
+    /// Returns a playable voicing of the chord made up of the given `notes`,
+    /// positioned around the given `fret`, or `None` if there is no such
+    /// voicing.
+    ///
+    /// The first of the `notes` is treated as the root of the chord, and is
+    /// always the lowest note of the voicing. The voicing is confined to a
+    /// four-fret hand position beginning one fret below `fret`; of the
+    /// voicings that fit, the one that sounds the most strings (and then
+    /// spans the fewest frets) is chosen. Strings that are not part of the
+    /// voicing should be muted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardLocation;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // B major, which is played as an 'E-shape' barre chord at the seventh fret
+    /// let notes = [
+    ///     Note::from_str("B").unwrap(),
+    ///     Note::from_str("Eb").unwrap(),
+    ///     Note::from_str("Gb").unwrap(),
+    /// ];
+    /// assert_eq!(
+    ///     guitar.voicing_near_fret(&notes, 7),
+    ///     Some(vec![
+    ///         FretboardLocation::new(1, 7),
+    ///         FretboardLocation::new(2, 7),
+    ///         FretboardLocation::new(3, 8),
+    ///         FretboardLocation::new(4, 9),
+    ///         FretboardLocation::new(5, 9),
+    ///         FretboardLocation::new(6, 7),
+    ///     ])
+    /// );
+    /// ```
+    pub fn voicing_near_fret(&self, notes: &[Note], fret: usize) -> Option<Vec<FretboardLocation>> {
+        let lowest_fret = fret.saturating_sub(1);
+        self.voicings_in_window(notes, lowest_fret, lowest_fret + HAND_SPAN - 1)
+            .into_iter()
+            .min_by_key(|voicing| {
+                let frets = voicing.iter().map(|loc| loc.fret_number);
+                let span = frets.clone().max().unwrap() - frets.min().unwrap();
+                (Reverse(voicing.len()), span)
+            })
+    }
+
+    /// Returns the numbers of the fewest strings that can together play every
+    /// one of the chord's `notes` within a single hand position, or `None` if
+    /// the chord cannot be played.
+    ///
+    /// As with `voicing_near_fret`, the first of the `notes` is treated as
+    /// the root, and must be the lowest note played.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // C major
+    /// let notes = [
+    ///     Note::from_str("C").unwrap(),
+    ///     Note::from_str("E").unwrap(),
+    ///     Note::from_str("G").unwrap(),
+    /// ];
+    /// // C on the A string, with the open G and high E strings
+    /// assert_eq!(guitar.minimal_strings_for_chord(&notes), Some(vec![1, 3, 5]));
+    /// ```
+    pub fn minimal_strings_for_chord(&self, notes: &[Note]) -> Option<Vec<usize>> {
+        (0..=self.num_frets)
+            .flat_map(|lowest_fret| {
+                self.voicings_in_window(notes, lowest_fret, lowest_fret + HAND_SPAN - 1)
+            })
+            .min_by_key(|voicing| voicing.len())
+            .map(|voicing| voicing.iter().map(|loc| loc.string_number).collect())
+    }
+
+    /// Returns the lowest fret played by any voicing of the chord made up of
+    /// the given `notes` that fits within `max_span` consecutive frets, or
+    /// `None` if the chord cannot be played. A result of 0 means that the
+    /// chord can be played with open strings.
+    ///
+    /// As with `voicing_near_fret`, the first of the `notes` is treated as
+    /// the root, and must be the lowest note played.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // E major can be played as an open chord
+    /// let e_major = [
+    ///     Note::from_str("E").unwrap(),
+    ///     Note::from_str("Ab").unwrap(),
+    ///     Note::from_str("B").unwrap(),
+    /// ];
+    /// assert_eq!(guitar.lowest_playable_fret(&e_major, 4), Some(0));
+    ///
+    /// // None of the open strings are in Gb major, so it is first playable
+    /// // as a barre chord
+    /// let gb_major = [
+    ///     Note::from_str("Gb").unwrap(),
+    ///     Note::from_str("Bb").unwrap(),
+    ///     Note::from_str("Db").unwrap(),
+    /// ];
+    /// assert_eq!(guitar.lowest_playable_fret(&gb_major, 4), Some(1));
+    ///
+    /// // Even with an unlimited span, none of the open strings are in the chord
+    /// assert_eq!(guitar.lowest_playable_fret(&gb_major, usize::MAX), Some(1));
+    /// ```
+    pub fn lowest_playable_fret(&self, notes: &[Note], max_span: usize) -> Option<usize> {
+        if max_span == 0 {
+            return None;
+        }
+
+        // A voicing whose lowest fret is `f` always fits in the window starting
+        // at `f`, so the first window with such a voicing gives the answer
+        (0..=self.num_frets).find(|&lowest_fret| {
+            let highest_fret = lowest_fret.saturating_add(max_span - 1).min(self.num_frets);
+            self.voicings_in_window(notes, lowest_fret, highest_fret)
+                .iter()
+                .any(|voicing| voicing.iter().map(|loc| loc.fret_number).min() == Some(lowest_fret))
+        })
+    }
+
+    /// Returns the highest fret reached by the easiest voicing of the chord
+    /// made up of the given `notes` that fits within a hand span, or `None` if
+    /// the chord cannot be played. Open chords are therefore the easiest, and
+    /// chords further up the neck are harder.
+    fn chord_difficulty(&self, notes: &[Note]) -> Option<usize> {
+        (0..=self.num_frets)
+            .flat_map(|lowest_fret| {
+                self.voicings_in_window(notes, lowest_fret, lowest_fret + HAND_SPAN - 1)
+            })
+            .filter_map(|voicing| voicing.iter().map(|loc| loc.fret_number).max())
+            .min()
+    }
+
+    /// Returns every voicing of the chord made up of the given `notes` that
+    /// lies between `lowest_fret` and `highest_fret` (inclusive).
+    ///
+    /// A voicing plays at most one location per string, includes every chord
+    /// tone, and has the first of the `notes` (the root) as its lowest note.
+    fn voicings_in_window(
+        &self,
+        notes: &[Note],
+        lowest_fret: usize,
+        highest_fret: usize,
+    ) -> Vec<Vec<FretboardLocation>> {
+        let root = match notes.first() {
+            Some(root) => root.disregard_octave(),
+            None => return Vec::new(),
+        };
+        let chord_tones: Vec<_> = notes.iter().map(|note| note.disregard_octave()).collect();
+        let highest_fret = highest_fret.min(self.num_frets);
+
+        // Builds up every combination of chord tones within the window, where
+        // each string is either muted or plays one of its chord tones
+        let mut voicings = vec![Vec::new()];
+        for (string_idx, string) in self.strings.iter().enumerate() {
+            let mut extended_voicings = Vec::new();
+            for voicing in voicings {
+                for fret_idx in lowest_fret..=highest_fret {
+                    if chord_tones.contains(&string.frets[fret_idx].disregard_octave()) {
+                        let mut extended_voicing = voicing.clone();
+                        extended_voicing.push(FretboardLocation::new(string_idx + 1, fret_idx));
+                        extended_voicings.push(extended_voicing);
+                    }
+                }
+
+                extended_voicings.push(voicing);
+            }
+
+            voicings = extended_voicings;
+        }
+
+        voicings
+            .into_iter()
+            .filter(|voicing| {
+                let voiced_notes: Vec<_> = voicing
+                    .iter()
+                    .map(|loc| self.strings[loc.string_number - 1].frets[loc.fret_number])
+                    .collect();
+
+                let has_all_tones = chord_tones.iter().all(|tone| {
+                    voiced_notes
+                        .iter()
+                        .any(|note| note.disregard_octave() == *tone)
+                });
+                let has_root_in_bass = voiced_notes
+                    .iter()
+                    .min_by_key(|note| note.value)
+                    .is_some_and(|bass| bass.disregard_octave() == root);
+
+                has_all_tones && has_root_in_bass
+            })
+            .collect()
+    }
+}
+
+/// A single guitar string, represented as the note values of
+/// each of its frets.
+#[derive(Debug)]
+pub struct GuitarString {
+    frets: Vec<Note>,
+}
+
+impl GuitarString {
+    /// Creates a new `GuitarString`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::GuitarString;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let e_string = GuitarString::new(Note::from_str("E2").unwrap(), 20);
+    /// ```
+    pub fn new(open_note: Note, num_frets: usize) -> Self {
+        Self {
+            // 1 is added to `num_frets` to include the open string
+            frets: open_note.into_iter().take(num_frets + 1).collect(),
+        }
+    }
+
+    /// Returns the note at the given `fret` of the string, or `None` if
+    /// the string does not have that many frets.
+    ///
+    /// A `fret` of 0 returns the open note.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::GuitarString;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let e_string = GuitarString::new(Note::from_str("E2").unwrap(), 20);
+    /// assert_eq!(e_string.note_at_fret(0), Some(Note::from_str("E2").unwrap()));
+    /// assert_eq!(e_string.note_at_fret(5), Some(Note::from_str("A2").unwrap()));
+    /// assert_eq!(e_string.note_at_fret(21), None);
+    /// ```
+    pub fn note_at_fret(&self, fret: usize) -> Option<Note> {
+        self.frets.get(fret).copied()
+    }
+
+    /// Returns the number of frets between the notes `a` and `b` on the
+    /// string, or `None` if either note cannot be played on it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::GuitarString;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let e_string = GuitarString::new(Note::from_str("E2").unwrap(), 20);
+    ///
+    /// // A whole step
+    /// let a = Note::from_str("G2").unwrap();
+    /// let b = Note::from_str("A2").unwrap();
+    /// assert_eq!(e_string.frets_between(a, b), Some(2));
+    /// assert_eq!(e_string.frets_between(b, a), Some(2));
+    ///
+    /// assert_eq!(e_string.frets_between(a, Note::from_str("D2").unwrap()), None);
+    /// ```
+    pub fn frets_between(&self, a: Note, b: Note) -> Option<usize> {
+        let a_fret = self.frets.iter().position(|fret| *fret == a)?;
+        let b_fret = self.frets.iter().position(|fret| *fret == b)?;
+        Some(a_fret.abs_diff(b_fret))
+    }
+
+    /// Returns the approximate tension (in newtons) of the string when it is
+    /// tuned to its open note, assuming that it is a plain steel string with
+    /// the given scale length and gauge (diameter, in inches).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::GuitarString;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let e4_string = GuitarString::new(Note::from_str("E4").unwrap(), 20);
+    /// let e5_string = GuitarString::new(Note::from_str("E5").unwrap(), 20);
+    ///
+    /// // A .010 gauge high E string on a 648 mm scale is under roughly 72.6 N
+    /// let tension = e4_string.tension(648.0, 0.010);
+    /// assert!((tension - 72.6).abs() < 0.1);
+    ///
+    /// // Tension is proportional to the square of the frequency, so tuning
+    /// // the same string up an octave quadruples it
+    /// let ratio = e5_string.tension(648.0, 0.010) / tension;
+    /// assert!((ratio - 4.0).abs() < 1e-9);
+    /// ```
+    pub fn tension(&self, scale_length_mm: f64, gauge_in: f64) -> f64 {
+        // The density of steel, in kilograms per cubic metre
+        const STEEL_DENSITY: f64 = 7850.0;
+
+        let radius_m = gauge_in * 0.0254 / 2.0;
+        let mass_per_metre = STEEL_DENSITY * std::f64::consts::PI * radius_m.powi(2);
+        let scale_length_m = scale_length_mm / 1000.0;
+
+        mass_per_metre * (2.0 * scale_length_m * frequency(self.frets[0])).powi(2)
+    }
+
+    /// Returns the note sounded by the natural harmonic at the given `fret`
+    /// of the string, or `None` if there is no natural harmonic there (or if
+    /// the string does not have that many frets).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::GuitarString;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let a_string = GuitarString::new(Note::from_str("A2").unwrap(), 20);
+    ///
+    /// // An octave above the open string
+    /// assert_eq!(a_string.harmonic_at(12), Some(Note::from_str("A3").unwrap()));
+    /// // An octave and a fifth above the open string
+    /// assert_eq!(a_string.harmonic_at(7), Some(Note::from_str("E4").unwrap()));
+    /// assert_eq!(a_string.harmonic_at(6), None);
+    /// ```
+    pub fn harmonic_at(&self, fret: usize) -> Option<Note> {
+        // The number of semitones between the open string and the harmonic,
+        // which depends on how the node divides the string
+        let semitones = match fret {
+            12 => 12,
+            7 | 19 => 19,
+            5 | 24 => 24,
+            4 | 9 | 16 => 28,
+            3 => 31,
+            _ => return None,
+        };
+
+        if fret >= self.frets.len() {
+            return None;
+        }
+
+        Some(self.frets[0] + semitones)
+    }
+}
+
+/// Returns the frequency (in hertz) of the given `note` in equal temperament,
+/// where A4 is tuned to 440 Hz.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// assert_eq!(gitar::frequency(Note::from_str("A4").unwrap()), 440.0);
+/// assert_eq!(gitar::frequency(Note::from_str("A3").unwrap()), 220.0);
+/// ```
+pub fn frequency(note: Note) -> f64 {
+    // A4 is 57 semitones above C0
+    440.0 * 2f64.powf((note.value as f64 - 57.0) / 12.0)
+}
+
+/// Returns the note whose equal-tempered frequency is nearest to the given
+/// `frequency` (in hertz), or `None` if it is below the range of `Note`
+/// (i.e. closer to a pitch below C0) or is not a finite, positive number.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// assert_eq!(gitar::nearest_note(445.0), Some(Note::from_str("A4").unwrap()));
+/// assert_eq!(gitar::nearest_note(10.0), None);
+/// assert_eq!(gitar::nearest_note(f64::INFINITY), None);
+/// assert_eq!(gitar::nearest_note(f64::NAN), None);
+/// ```
+pub fn nearest_note(frequency: f64) -> Option<Note> {
+    if !frequency.is_finite() || frequency <= 0.0 {
+        return None;
+    }
+
+    let value = (57.0 + 12.0 * (frequency / 440.0).log2()).round();
+    if value >= 0.0 {
+        Some(Note::new(value as usize))
+    } else {
+        None
+    }
+}
+
+/// Parses a `Note` from user input, producing a descriptive error message
+/// if the input is not a valid note.
+///
+/// Unlike `Note::from_str`, note names are case-insensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// assert_eq!(gitar::parse_note("Ab3").unwrap(), Note::from_str("Ab3").unwrap());
+/// assert_eq!(gitar::parse_note("ab3").unwrap(), Note::from_str("Ab3").unwrap());
+/// assert_eq!(gitar::parse_note("c").unwrap(), Note::from_str("C").unwrap());
+/// assert_eq!(gitar::parse_note("EB").unwrap(), Note::from_str("Eb").unwrap());
+///
+/// let error = gitar::parse_note("H").unwrap_err();
+/// assert!(error.to_string().contains("C, Db, D, Eb, E, F, Gb, G, Ab, A, Bb, B"));
+/// ```
+pub fn parse_note(s: &str) -> anyhow::Result<Note> {
+    // Normalises the input to an uppercase letter followed by a lowercase
+    // accidental, which is the only form that `Note::from_str` accepts
+    let mut chars = s.chars();
+    let normalised: String = match chars.next() {
+        Some(letter) => letter
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    };
+
+    Note::from_str(&normalised).map_err(|_| {
+        anyhow::anyhow!(
+            "'{}' is not a valid note; note names must be one of C, Db, D, Eb, E, F, \
+             Gb, G, Ab, A, Bb, B, optionally followed by an octave number (e.g. Ab3)",
+            s
+        )
+    })
+}
+
+/// Returns the letter (from A to G) of the given `note`'s name, ignoring any
+/// accidental and the octave.
+///
+/// Notes are spelled with flats (see `Note`'s `Display` implementation), so
+/// C sharp is spelled Db and has the letter D.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// assert_eq!(gitar::note_letter(Note::from_str("E2").unwrap()), 'E');
+/// assert_eq!(gitar::note_letter(Note::from_str("Db4").unwrap()), 'D');
+/// assert_eq!(gitar::note_letter(Note::from_str("Bb").unwrap()), 'B');
+/// ```
+pub fn note_letter(note: Note) -> char {
+    note.to_string()
+        .chars()
+        .next()
+        .expect("note names are never empty")
+}
+
+/// Standard, six-string guitar tuning.
+pub fn standard_tuning() -> Vec<Note> {
+    vec![
+        Note::from_str("E2").unwrap(),
+        Note::from_str("A2").unwrap(),
+        Note::from_str("D3").unwrap(),
+        Note::from_str("G3").unwrap(),
+        Note::from_str("B3").unwrap(),
+        Note::from_str("E4").unwrap(),
+    ]
+}
+
+/// Standard seven-string guitar tuning, which adds a low B string below
+/// standard six-string tuning.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let tuning = gitar::standard_seven_string_tuning();
+/// assert_eq!(tuning.len(), 7);
+/// assert_eq!(tuning[0], Note::from_str("B1").unwrap());
+/// assert_eq!(tuning[1..], gitar::standard_tuning()[..]);
+/// ```
+pub fn standard_seven_string_tuning() -> Vec<Note> {
+    let mut tuning = vec![Note::from_str("B1").unwrap()];
+    tuning.extend(standard_tuning());
+    tuning
+}
+
+/// Returns the named preset tuning for the given string `instrument`, or
+/// `None` if there is no such preset.
+///
+/// The available presets are:
+///
+/// * `guitar`: `standard`, `seven-string`, `drop-d`
+/// * `bass`: `standard`
+/// * `ukulele`: `standard`
+/// * `mandolin`, `violin`, `viola`, `cello`: `standard`
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     gitar::preset_tuning("mandolin", "standard"),
+///     Some(vec![
+///         Note::from_str("G3").unwrap(),
+///         Note::from_str("D4").unwrap(),
+///         Note::from_str("A4").unwrap(),
+///         Note::from_str("E5").unwrap(),
+///     ])
+/// );
+/// assert_eq!(gitar::preset_tuning("guitar", "standard"), Some(gitar::standard_tuning()));
+/// assert_eq!(gitar::preset_tuning("banjo", "standard"), None);
+/// ```
+pub fn preset_tuning(instrument: &str, name: &str) -> Option<Vec<Note>> {
+    let tuning: &[&str] = match (instrument, name) {
+        ("guitar", "standard") => return Some(standard_tuning()),
+        ("guitar", "seven-string") => return Some(standard_seven_string_tuning()),
+        ("guitar", "drop-d") => &["D2", "A2", "D3", "G3", "B3", "E4"],
+        ("bass", "standard") => &["E1", "A1", "D2", "G2"],
+        ("ukulele", "standard") => &["G4", "C4", "E4", "A4"],
+        ("mandolin", "standard") | ("violin", "standard") => &["G3", "D4", "A4", "E5"],
+        ("viola", "standard") => &["C3", "G3", "D4", "A4"],
+        ("cello", "standard") => &["C2", "G2", "D3", "A3"],
+        _ => return None,
+    };
+
+    Some(
+        tuning
+            .iter()
+            .map(|note| Note::from_str(note).unwrap())
+            .collect(),
+    )
+}
+
+/// Returns a tuning made up of the given note `names`, ignoring their octaves,
+/// with octaves chosen so that the strings ascend from the lowest: the first
+/// note is placed in octave 2, and each following note is the first one above
+/// the note before it.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let names: Vec<_> = ["E", "A", "D", "G", "B", "E"]
+///     .iter()
+///     .map(|name| Note::from_str(name).unwrap())
+///     .collect();
+/// assert_eq!(gitar::infer_tuning_octaves(&names), gitar::standard_tuning());
+/// ```
+pub fn infer_tuning_octaves(names: &[Note]) -> Vec<Note> {
+    let mut tuning: Vec<Note> = Vec::with_capacity(names.len());
+    for name in names {
+        let pitch_class = name.disregard_octave();
+        let note = match tuning.last() {
+            Some(&previous) => {
+                let interval = (pitch_class.value + 12 - previous.disregard_octave().value) % 12;
+                previous + if interval == 0 { 12 } else { interval }
+            }
+            None => pitch_class + 24,
+        };
+        tuning.push(note);
+    }
+
+    tuning
+}
+
+/// Returns the intervals (in semitones) between each pair of adjacent
+/// strings in the given `tuning`. An interval is negative if the higher
+/// string is tuned lower than the string before it, as in reentrant tunings.
+///
+/// # Examples
+///
+/// ```rust
+/// // Standard tuning is made up of perfect fourths, apart from the major
+/// // third between the G and B strings
+/// assert_eq!(
+///     gitar::tuning_intervals(&gitar::standard_tuning()),
+///     vec![5, 5, 5, 4, 5]
+/// );
+///
+/// // The G string of a standard ukulele is tuned above the C string
+/// assert_eq!(
+///     gitar::tuning_intervals(&gitar::preset_tuning("ukulele", "standard").unwrap()),
+///     vec![-7, 4, 5]
+/// );
+/// ```
+pub fn tuning_intervals(tuning: &[Note]) -> Vec<isize> {
+    tuning
+        .windows(2)
+        .map(|pair| pair[1].value as isize - pair[0].value as isize)
+        .collect()
+}
+
+/// Returns the given `tuning` with its open notes cyclically rotated by `n`
+/// strings, so that each string takes the open note of the string `n` above
+/// it (and the highest strings wrap around to the lowest).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let rotated = gitar::rotate_tuning(&gitar::standard_tuning(), 1);
+/// assert_eq!(
+///     rotated,
+///     vec![
+///         Note::from_str("A2").unwrap(),
+///         Note::from_str("D3").unwrap(),
+///         Note::from_str("G3").unwrap(),
+///         Note::from_str("B3").unwrap(),
+///         Note::from_str("E4").unwrap(),
+///         Note::from_str("E2").unwrap(),
+///     ]
+/// );
+/// ```
+pub fn rotate_tuning(tuning: &[Note], n: usize) -> Vec<Note> {
+    let mut rotated = tuning.to_vec();
+    if !rotated.is_empty() {
+        let len = rotated.len();
+        rotated.rotate_left(n % len);
+    }
+
+    rotated
+}
+
+/// Returns the given `tuning` (from the lowest string to the highest) shifted
+/// so that its lowest string is tuned to `new_lowest`, keeping the intervals
+/// between every pair of adjacent strings the same. Returns `None` if this
+/// would tune any string below C0, which can happen if a higher string is
+/// tuned lower than the lowest (as in reentrant tunings).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// // Standard tuning, a whole step down
+/// let retuned = gitar::retune_to_pitch(&gitar::standard_tuning(), Note::from_str("D2").unwrap());
+/// assert_eq!(
+///     retuned,
+///     Some(vec![
+///         Note::from_str("D2").unwrap(),
+///         Note::from_str("G2").unwrap(),
+///         Note::from_str("C3").unwrap(),
+///         Note::from_str("F3").unwrap(),
+///         Note::from_str("A3").unwrap(),
+///         Note::from_str("D4").unwrap(),
+///     ])
+/// );
+///
+/// // The C string of a standard ukulele is below its G string, so it can't be
+/// // tuned down this far
+/// let ukulele = gitar::preset_tuning("ukulele", "standard").unwrap();
+/// assert_eq!(gitar::retune_to_pitch(&ukulele, Note::from_str("C0").unwrap()), None);
+/// ```
+pub fn retune_to_pitch(tuning: &[Note], new_lowest: Note) -> Option<Vec<Note>> {
+    let lowest = match tuning.first() {
+        Some(&lowest) => lowest,
+        None => return Some(Vec::new()),
+    };
+
+    tuning
+        .iter()
+        .map(|&note| {
+            if new_lowest.value >= lowest.value {
+                Some(note + (new_lowest - lowest))
+            } else {
+                note.value.checked_sub(lowest - new_lowest).map(Note::new)
+            }
+        })
+        .collect()
+}
+
+/// Returns whether the given `tuning` is regular, i.e. whether every pair of
+/// adjacent strings is separated by the same interval (as in all-fourths or
+/// major-thirds tuning).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let all_fourths: Vec<_> = ["E2", "A2", "D3", "G3", "C4", "F4"]
+///     .iter()
+///     .map(|note| Note::from_str(note).unwrap())
+///     .collect();
+/// assert!(gitar::is_regular_tuning(&all_fourths));
+///
+/// // Standard tuning has a major third between the G and B strings
+/// assert!(!gitar::is_regular_tuning(&gitar::standard_tuning()));
+///
+/// // Going up a fourth and back down again is not a regular tuning
+/// let up_and_down: Vec<_> = ["E2", "A2", "E2"]
+///     .iter()
+///     .map(|note| Note::from_str(note).unwrap())
+///     .collect();
+/// assert!(!gitar::is_regular_tuning(&up_and_down));
+/// ```
+pub fn is_regular_tuning(tuning: &[Note]) -> bool {
+    tuning_intervals(tuning)
+        .windows(2)
+        .all(|pair| pair[0] == pair[1])
+}
+
+/// Returns the tuning from `candidates` (each from the lowest string to the
+/// highest) that makes the given `chords` easiest to play, or `None` if there
+/// are no candidates.
+///
+/// Each chord is scored by the highest fret that its easiest voicing reaches
+/// (so open chords score best), and the tuning with the lowest total is
+/// chosen. As with `Guitar::voicing_near_fret()`, the first note of each
+/// chord is treated as its root.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let notes = |names: &[&str]| -> Vec<Note> {
+///     names.iter().map(|name| Note::from_str(name).unwrap()).collect()
+/// };
+/// let open_d = notes(&["D2", "A2", "D3", "Gb3", "A3", "D4"]);
+/// let candidates = [gitar::standard_tuning(), open_d.clone()];
+///
+/// // Open D tuning lets D major ring open
+/// let d_major = notes(&["D", "Gb", "A"]);
+/// assert_eq!(gitar::suggest_tuning(&[d_major.clone()], &candidates), Some(open_d));
+///
+/// // A major is awkward in open D, though
+/// let a_major = notes(&["A", "Db", "E"]);
+/// assert_eq!(
+///     gitar::suggest_tuning(&[d_major, a_major], &candidates),
+///     Some(gitar::standard_tuning())
+/// );
+/// ```
+pub fn suggest_tuning(chords: &[Vec<Note>], candidates: &[Vec<Note>]) -> Option<Vec<Note>> {
+    // Chord shapes repeat an octave up the neck, so the first octave (plus a
+    // hand span) contains the easiest voicing of every chord
+    let num_frets = 12 + HAND_SPAN;
+
+    candidates
+        .iter()
+        .min_by_key(|tuning| {
+            let guitar = Luthier::new(num_frets).string(tuning.to_vec()).build();
+            chords
+                .iter()
+                .map(|chord| guitar.chord_difficulty(chord).unwrap_or(num_frets + 1))
+                .sum::<usize>()
+        })
+        .cloned()
+}
+
+/// Returns the distance (in millimetres) between the nut and the given `fret`
+/// of a string with the given scale length, using the exact equal-tempered
+/// formula `L * (1 - 1 / 2^(fret / 12))` (rather than the 'rule of 18'
+/// approximation).
+///
+/// # Examples
+///
+/// ```rust
+/// // The twelfth fret lies halfway along the string
+/// assert_eq!(gitar::fret_distance_from_nut(12, 648.0), 324.0);
+/// assert_eq!(gitar::fret_distance_from_nut(0, 648.0), 0.0);
+/// ```
+pub fn fret_distance_from_nut(fret: usize, scale_length_mm: f64) -> f64 {
+    scale_length_mm * (1.0 - 1.0 / 2f64.powf(fret as f64 / 12.0))
+}
+
+/// Returns the root of the chord that sounds when a chord shape with the
+/// given `shape_root` is played with a capo on `capo_fret`.
+///
+/// The returned note holds no octave information (see
+/// `Note::disregard_octave()`).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// // An open C shape with a capo on the second fret sounds as D
+/// assert_eq!(
+///     gitar::sounding_chord(Note::from_str("C").unwrap(), 2),
+///     Note::from_str("D").unwrap()
+/// );
+///
+/// // An open G shape with a capo on the fifth fret sounds as C
+/// assert_eq!(
+///     gitar::sounding_chord(Note::from_str("G").unwrap(), 5),
+///     Note::from_str("C").unwrap()
+/// );
+/// ```
+pub fn sounding_chord(shape_root: Note, capo_fret: usize) -> Note {
+    (shape_root + capo_fret).disregard_octave()
+}
+
+/// Returns the note that is the given number of diatonic `steps` above (or
+/// below, if `steps` is negative) `note` in the given `key`, or `None` if
+/// `note` is not in the key or the result would be lower than C0.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Key, Mode, Note};
+/// use std::str::FromStr;
+///
+/// let c_major = Key::new(Note::new(0), Mode::Ionian);
+/// let step = |note, steps| gitar::step_in_key(Note::from_str(note).unwrap(), c_major, steps);
+///
+/// // A whole step from C, but a half step from E
+/// assert_eq!(step("C4", 1), Some(Note::from_str("D4").unwrap()));
+/// assert_eq!(step("E4", 1), Some(Note::from_str("F4").unwrap()));
+///
+/// // Steps wrap around into neighbouring octaves
+/// assert_eq!(step("B3", 1), Some(Note::from_str("C4").unwrap()));
+/// assert_eq!(step("C4", -1), Some(Note::from_str("B3").unwrap()));
+/// assert_eq!(step("A4", -7), Some(Note::from_str("A3").unwrap()));
+///
+/// // C sharp isn't in C major
+/// assert_eq!(step("Db4", 1), None);
+/// ```
+pub fn step_in_key(note: Note, key: Key, steps: i32) -> Option<Note> {
+    let scale = key.notes_disregarding_octave();
+    let mut degree = scale
+        .iter()
+        .position(|&scale_note| scale_note == note.disregard_octave())?;
+
+    let mut note = note;
+    for _ in 0..steps.unsigned_abs() {
+        if steps > 0 {
+            let next_degree = (degree + 1) % scale.len();
+            note = note + (scale[next_degree].value + 12 - scale[degree].value) % 12;
+            degree = next_degree;
+        } else {
+            let prev_degree = (degree + scale.len() - 1) % scale.len();
+            let interval = (scale[degree].value + 12 - scale[prev_degree].value) % 12;
+            note = Note::new(note.value.checked_sub(interval)?);
+            degree = prev_degree;
+        }
+    }
+
+    Some(note)
+}
+
+/// Returns the note in the given `key` that is closest in pitch to `note`
+/// (which is returned unchanged if it is already in the key).
+///
+/// Ties resolve upward. Because neighbouring notes of a key are never more
+/// than a whole step apart, every note outside a key lies a half step from
+/// two notes of it, and so snaps up to the higher one.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Key, Mode, Note};
+/// use std::str::FromStr;
+///
+/// let c_major = Key::new(Note::new(0), Mode::Ionian);
+/// let nearest = |name| gitar::nearest_in_key(Note::from_str(name).unwrap(), c_major);
+///
+/// assert_eq!(nearest("E4"), Note::from_str("E4").unwrap());
+/// // C sharp is a half step from both C and D
+/// assert_eq!(nearest("Db4"), Note::from_str("D4").unwrap());
+/// assert_eq!(nearest("Bb3"), Note::from_str("B3").unwrap());
+/// ```
+pub fn nearest_in_key(note: Note, key: Key) -> Note {
+    let scale = key.notes_disregarding_octave();
+    let in_key = |note: &Note| scale.contains(&note.disregard_octave());
+
+    // Every note outside a key is a half step from it (see above)
+    for distance in 0..=1 {
+        let above = note + distance;
+        if in_key(&above) {
+            return above;
+        }
+
+        if let Some(value) = note.value.checked_sub(distance) {
+            let below = Note::new(value);
+            if in_key(&below) {
+                return below;
+            }
+        }
+    }
+
+    unreachable!("every note is within a half step of a key")
+}
+
+/// Returns `note` moved up or down by whole octaves until it lies between
+/// `low` and `high` (inclusive), such as the range given by
+/// `Guitar::lowest_note()` and `Guitar::highest_note()`.
+///
+/// If the range spans less than an octave and no octave of `note` falls
+/// within it, the lowest octave of `note` above `low` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+/// let (low, high) = (guitar.lowest_note().unwrap(), guitar.highest_note().unwrap());
+/// let clamp = |name| gitar::clamp_to_range(Note::from_str(name).unwrap(), low, high);
+///
+/// // Too low for a guitar, whose lowest note is E2
+/// assert_eq!(clamp("C1"), Note::from_str("C3").unwrap());
+/// // Too high for a guitar, whose highest note is Db6
+/// assert_eq!(clamp("G7"), Note::from_str("G5").unwrap());
+/// // Already in range
+/// assert_eq!(clamp("A3"), Note::from_str("A3").unwrap());
+/// ```
+pub fn clamp_to_range(note: Note, low: Note, high: Note) -> Note {
+    let mut note = note;
+    while note.value < low.value {
+        note = note + 12;
+    }
+    while note.value > high.value && note.value >= low.value + 12 {
+        note = note - 12;
+    }
+
+    note
+}
+
+/// Returns the number of semitones to shift the given `melody` by (down, if
+/// negative) so that it best fits between `low` and `high` (inclusive), such
+/// as a singer's range.
+///
+/// The best shift is the one that leaves the fewest notes out of range. Ties
+/// resolve to the smallest shift, and then to the downward one.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let notes = |names: &[&str]| -> Vec<Note> {
+///     names.iter().map(|name| Note::from_str(name).unwrap()).collect()
+/// };
+/// let (low, high) = (Note::from_str("G3").unwrap(), Note::from_str("C5").unwrap());
+///
+/// // The melody fits once it is shifted down an octave
+/// let melody = notes(&["E5", "G5", "A5", "C6"]);
+/// assert_eq!(gitar::best_transposition(&melody, low, high), -12);
+///
+/// // A melody that already fits is left as it is
+/// let melody = notes(&["A3", "B3", "C4"]);
+/// assert_eq!(gitar::best_transposition(&melody, low, high), 0);
+/// ```
+pub fn best_transposition(melody: &[Note], low: Note, high: Note) -> i32 {
+    let lowest = match melody.iter().map(|note| note.value).min() {
+        Some(lowest) => lowest as i32,
+        None => return 0,
+    };
+
+    // Shifting further down would take the melody below C0, and shifting
+    // further up would take all of it above `high`
+    let shifts = -lowest..=(high.value as i32 - lowest).max(0);
+    shifts
+        .min_by_key(|&shift| {
+            let out_of_range = melody
+                .iter()
+                .filter(|note| {
+                    let value = note.value as i32 + shift;
+                    value < low.value as i32 || value > high.value as i32
+                })
+                .count();
+            (out_of_range, shift.abs(), shift)
+        })
+        .unwrap_or(0)
+}
+
+/// Returns the five pitch classes that are not in the given `key`, in
+/// ascending order from C. These are the 'avoid notes' of the key.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Key, Mode, Note};
+/// use std::str::FromStr;
+///
+/// // The complement of C major is the black keys of a piano
+/// let c_major = Key::new(Note::new(0), Mode::Ionian);
+/// let black_keys: Vec<_> = ["Db", "Eb", "Gb", "Ab", "Bb"]
+///     .iter()
+///     .map(|name| Note::from_str(name).unwrap())
+///     .collect();
+/// assert_eq!(gitar::key_complement(c_major), black_keys);
+/// ```
+pub fn key_complement(key: Key) -> Vec<Note> {
+    let scale = key.notes_disregarding_octave();
+    (0..12)
+        .map(Note::new)
+        .filter(|note| !scale.contains(note))
+        .collect()
+}
+
+/// Returns the chord `notes` that are in the given `key`, in the order that
+/// they were given. These are the chord tones that can safely be played over
+/// the chord when improvising in the key.
+///
+/// Octaves are disregarded when comparing notes.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Key, Mode, Note};
+/// use std::str::FromStr;
+///
+/// let notes = |names: &[&str]| -> Vec<Note> {
+///     names.iter().map(|name| Note::from_str(name).unwrap()).collect()
+/// };
+/// let c_major = Key::new(Note::from_str("C").unwrap(), Mode::Ionian);
+/// let g_major = Key::new(Note::from_str("G").unwrap(), Mode::Ionian);
+///
+/// // Every tone of a C major chord is in both C major and G major
+/// let c_chord = notes(&["C", "E", "G"]);
+/// assert_eq!(gitar::chord_tones_in_scale(&c_chord, c_major), c_chord);
+/// assert_eq!(gitar::chord_tones_in_scale(&c_chord, g_major), c_chord);
+///
+/// // G major has an F sharp, so the F of an F major chord is left out
+/// let f_chord = notes(&["F", "A", "C"]);
+/// assert_eq!(gitar::chord_tones_in_scale(&f_chord, g_major), notes(&["A", "C"]));
+/// ```
+pub fn chord_tones_in_scale(notes: &[Note], key: Key) -> Vec<Note> {
+    let scale = key.notes_disregarding_octave();
+    notes
+        .iter()
+        .copied()
+        .filter(|note| scale.contains(&note.disregard_octave()))
+        .collect()
+}
+
+/// Returns every occurence of the chord `notes` (in any octave) between `low`
+/// and `high` (inclusive), in ascending order. This is useful for practicing
+/// arpeggios across several octaves.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let notes = |names: &[&str]| -> Vec<Note> {
+///     names.iter().map(|name| Note::from_str(name).unwrap()).collect()
+/// };
+///
+/// // C major across two octaves
+/// let c_major = notes(&["C", "E", "G"]);
+/// assert_eq!(
+///     gitar::chord_notes_in_range(
+///         &c_major,
+///         Note::from_str("C3").unwrap(),
+///         Note::from_str("C5").unwrap()
+///     ),
+///     notes(&["C3", "E3", "G3", "C4", "E4", "G4", "C5"])
+/// );
+/// ```
+pub fn chord_notes_in_range(notes: &[Note], low: Note, high: Note) -> Vec<Note> {
+    let chord_tones: Vec<_> = notes.iter().map(|note| note.disregard_octave()).collect();
+    low.into_iter()
+        .take_while(|note| note.value <= high.value)
+        .filter(|note| chord_tones.contains(&note.disregard_octave()))
+        .collect()
+}
+
+/// Returns the seven triads of the given `key`, built by stacking two thirds
+/// on each of its notes in turn, from the root upward. Notes are given
+/// without regard for octave (see `Note::disregard_octave()`).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Key, Mode, Note};
+/// use std::str::FromStr;
+///
+/// let triad = |names: [&str; 3]| names.map(|name| Note::from_str(name).unwrap());
+///
+/// let c_major = Key::new(Note::new(0), Mode::Ionian);
+/// assert_eq!(
+///     gitar::diatonic_triads(c_major),
+///     vec![
+///         triad(["C", "E", "G"]), // C
+///         triad(["D", "F", "A"]), // Dm
+///         triad(["E", "G", "B"]), // Em
+///         triad(["F", "A", "C"]), // F
+///         triad(["G", "B", "D"]), // G
+///         triad(["A", "C", "E"]), // Am
+///         triad(["B", "D", "F"]), // Bdim
+///     ]
+/// );
+/// ```
+pub fn diatonic_triads(key: Key) -> Vec<[Note; 3]> {
+    let scale = key.notes_disregarding_octave();
+    (0..scale.len())
+        .map(|degree| {
+            [
+                scale[degree],
+                scale[(degree + 2) % scale.len()],
+                scale[(degree + 4) % scale.len()],
+            ]
+        })
+        .collect()
+}
+
+/// Returns the interval vector of the chord made up of the given `notes`,
+/// i.e. how many times each interval class (from a minor second up to a
+/// tritone) occurs between the chord's distinct pitch classes.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let c_major: Vec<_> = ["C3", "E3", "G3"]
+///     .iter()
+///     .map(|note| Note::from_str(note).unwrap())
+///     .collect();
+/// assert_eq!(gitar::interval_vector(&c_major), [0, 0, 1, 1, 1, 0]);
+/// ```
+pub fn interval_vector(notes: &[Note]) -> [usize; 6] {
+    let mut pitch_classes: Vec<_> = notes.iter().map(|note| note.value % 12).collect();
+    pitch_classes.sort_unstable();
+    pitch_classes.dedup();
+
+    let mut vector = [0; 6];
+    for (i, a) in pitch_classes.iter().enumerate() {
+        for b in &pitch_classes[i + 1..] {
+            let interval = b - a;
+            vector[interval.min(12 - interval) - 1] += 1;
+        }
+    }
+
+    vector
+}
+
+/// A diagram of a `Guitar` fretboard, depicting the locations of certain notes.
+pub struct FretboardDiagram<'g> {
+    guitar: &'g Guitar,
+    locations: Vec<FretboardLocation>,
+    mute_unused_strings: bool,
+    fret_numbering_base: usize,
+    landscape: bool,
+}
+
+impl<'g> FretboardDiagram<'g> {
+    /// Creates a new `FretboardDiagram` based on the given `guitar` and
+    /// fretboard `locations`.
+    pub fn new(guitar: &'g Guitar, locations: Vec<FretboardLocation>) -> Self {
+        Self {
+            guitar,
+            locations,
+            mute_unused_strings: false,
+            fret_numbering_base: 0,
+            landscape: false,
+        }
+    }
+
+    /// Creates a new `FretboardDiagram` like `new()`, but returns an error if
+    /// any of the `locations` is on a string that the `guitar` does not have.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let bass = gitar::Luthier::new(20)
+    ///     .string(gitar::preset_tuning("bass", "standard").unwrap())
+    ///     .build();
+    ///
+    /// assert!(FretboardDiagram::try_new(&bass, vec![FretboardLocation::new(4, 3)]).is_ok());
+    ///
+    /// let error = FretboardDiagram::try_new(&bass, vec![FretboardLocation::new(6, 3)])
+    ///     .err()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "location 6-3 is on string 6, but the guitar only has 4 strings"
+    /// );
+    /// ```
+    pub fn try_new(guitar: &'g Guitar, locations: Vec<FretboardLocation>) -> anyhow::Result<Self> {
+        let num_strings = guitar.strings.len();
+        if let Some(loc) = locations
+            .iter()
+            .find(|loc| !(1..=num_strings).contains(&loc.string_number))
+        {
+            anyhow::bail!(
+                "location {} is on string {}, but the guitar only has {} strings",
+                loc,
+                loc.string_number,
+                num_strings
+            );
+        }
+
+        Ok(Self::new(guitar, locations))
+    }
+
+    /// Creates a blank `FretboardDiagram` of the given `guitar`'s whole
+    /// fretboard, with no locations marked. This is useful for printing
+    /// practice sheets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::FretboardDiagram;
+    ///
+    /// let guitar = gitar::Luthier::new(12)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let diagram = FretboardDiagram::blank(&guitar).to_string();
+    /// // One row for the open strings, followed by one for each fret
+    /// assert_eq!(diagram.lines().count(), 13);
+    /// assert!(!diagram.contains('∗'));
+    /// ```
+    pub fn blank(guitar: &'g Guitar) -> Self {
+        Self::new(guitar, Vec::new())
+    }
+
+    /// Marks every string without a location as muted, drawing an `x` above
+    /// it in a header line. This is useful when depicting chord voicings.
+    ///
+    /// The header line is left out if every string has a location.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // A four-note C major voicing that leaves both E strings unplayed
+    /// let locations = vec![
+    ///     FretboardLocation::new(5, 3),
+    ///     FretboardLocation::new(4, 2),
+    ///     FretboardLocation::new(3, 0),
+    ///     FretboardLocation::new(2, 1),
+    /// ];
+    ///
+    /// let diagram = FretboardDiagram::new(&guitar, locations).mute_unused_strings();
+    /// assert_eq!(
+    ///     diagram.to_string(),
+    ///     "x    x\n\
+    ///      ---∗-- 0\n\
+    ///      ││││∗│ 1\n\
+    ///      ││∗│││ 2\n\
+    ///      │∗││││ 3\n"
+    /// );
+    ///
+    /// // An open E minor chord uses every string
+    /// let e_minor = vec![
+    ///     FretboardLocation::new(6, 0),
+    ///     FretboardLocation::new(5, 2),
+    ///     FretboardLocation::new(4, 2),
+    ///     FretboardLocation::new(3, 0),
+    ///     FretboardLocation::new(2, 0),
+    ///     FretboardLocation::new(1, 0),
+    /// ];
+    /// let diagram = FretboardDiagram::new(&guitar, e_minor).mute_unused_strings();
+    /// assert_eq!(diagram.lines(), vec!["∗--∗∗∗ 0", "││││││ 1", "│∗∗│││ 2"]);
+    /// ```
+    pub fn mute_unused_strings(mut self) -> Self {
+        self.mute_unused_strings = true;
+        self
+    }
+
+    /// Returns each line of the rendered diagram separately, which is useful
+    /// for composing diagrams side by side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let locations = vec![FretboardLocation::new(6, 3), FretboardLocation::new(5, 5)];
+    /// let lines = FretboardDiagram::new(&guitar, locations).lines();
+    /// assert_eq!(lines, vec!["∗│││││ 3", "││││││ 4", "│∗││││ 5"]);
+    /// ```
+    pub fn lines(&self) -> Vec<String> {
+        let model = self.grid_model();
+        if self.landscape {
+            return Self::landscape_lines(&model);
+        }
+
+        let mut lines = Vec::new();
+
+        if !model.muted_strings.is_empty() {
+            let mut header = String::new();
+            for string_num in (1..=model.num_strings).rev() {
+                if model.muted_strings.contains(&string_num) {
+                    header.push('x');
+                } else {
+                    header.push(' ');
+                }
+            }
+
+            lines.push(header.trim_end().to_string());
+        }
+
+        // Draws a fretboard diagram showing all of the note locations
+        let fret_range = model.lowest_fret..=model.highest_fret;
+        for (fret_idx, fret_label) in fret_range.zip(&model.fret_labels) {
+            let mut line = String::new();
+            for string_num in (1..=model.num_strings).rev() {
+                let current_loc = model
+                    .markers
+                    .iter()
+                    .find(|loc| loc.string_number == string_num && loc.fret_number == fret_idx);
+
+                if let Some(loc) = current_loc {
+                    match loc.finger {
+                        Some(finger) => line.push_str(&finger.to_string()),
+                        None => line.push('∗'),
+                    }
+                } else if fret_idx == 0 {
+                    line.push('-');
+                } else {
+                    line.push('│');
+                }
+            }
+
+            line.push_str(&format!(" {}", fret_label));
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    /// Draws the diagram described by `model` with one row per string (the
+    /// highest at the top) and frets progressing from left to right.
+    fn landscape_lines(model: &DiagramModel) -> Vec<String> {
+        let show_muted = !model.muted_strings.is_empty();
+        let mut lines = Vec::new();
+
+        for string_num in 1..=model.num_strings {
+            let mut line = String::new();
+            if show_muted {
+                if model.muted_strings.contains(&string_num) {
+                    line.push_str("x ");
+                } else {
+                    line.push_str("  ");
+                }
+            }
+
+            for fret_idx in model.lowest_fret..=model.highest_fret {
+                let current_loc = model
+                    .markers
+                    .iter()
+                    .find(|loc| loc.string_number == string_num && loc.fret_number == fret_idx);
+                let line_char = if fret_idx == 0 { '-' } else { '─' };
+
+                line.push(line_char);
+                match current_loc {
+                    Some(FretboardLocation {
+                        finger: Some(finger),
+                        ..
+                    }) => line.push_str(&finger.to_string()),
+                    Some(_) => line.push('∗'),
+                    None => line.push(line_char),
+                }
+                line.push(line_char);
+            }
+
+            lines.push(line);
+        }
+
+        // Labels each fret beneath the centre of its column
+        let mut fret_labels = String::new();
+        if show_muted {
+            fret_labels.push_str("  ");
+        }
+        for fret_label in &model.fret_labels {
+            fret_labels.push_str(&format!("{:^3}", fret_label));
+        }
+        lines.push(fret_labels.trim_end().to_string());
+
+        lines
+    }
+
+    /// Returns a structured description of the diagram, so that it can be
+    /// drawn by external renderers (e.g. into an image) without parsing the
+    /// text output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{DiagramModel, FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let locations = vec![FretboardLocation::new(5, 3), FretboardLocation::new(4, 2)];
+    /// let diagram = FretboardDiagram::new(&guitar, locations.clone()).mute_unused_strings();
+    /// assert_eq!(
+    ///     diagram.grid_model(),
+    ///     DiagramModel {
+    ///         num_strings: 6,
+    ///         lowest_fret: 2,
+    ///         highest_fret: 3,
+    ///         shows_nut: false,
+    ///         markers: locations,
+    ///         muted_strings: vec![1, 2, 3, 6],
+    ///         fret_labels: vec![2, 3],
+    ///     }
+    /// );
+    /// ```
+    pub fn grid_model(&self) -> DiagramModel {
+        let num_strings = self.guitar.strings.len();
+
+        // Depicts the whole fretboard if there are no locations (i.e. if the
+        // diagram is blank)
+        let fret_numbers = self.locations.iter().map(|loc| loc.fret_number);
+        let lowest_fret = fret_numbers.clone().min().unwrap_or(0);
+        let highest_fret = fret_numbers.max().unwrap_or(self.guitar.num_frets);
+
+        let muted_strings = if self.mute_unused_strings {
+            (1..=num_strings)
+                .filter(|&string_num| {
+                    !self
+                        .locations
+                        .iter()
+                        .any(|loc| loc.string_number == string_num)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        DiagramModel {
+            num_strings,
+            lowest_fret,
+            highest_fret,
+            shows_nut: lowest_fret == 0,
+            markers: self.locations.clone(),
+            muted_strings,
+            fret_labels: (lowest_fret..=highest_fret)
+                .map(|fret_idx| fret_idx + self.fret_numbering_base)
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of the diagram with every location slid up (or down,
+    /// if `frets` is negative) the neck by the given number of frets.
+    ///
+    /// Locations that would be moved off the fretboard are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // An F major barre chord, slid up to G major
+    /// let locations = vec![
+    ///     FretboardLocation::new(6, 1),
+    ///     FretboardLocation::new(5, 3),
+    ///     FretboardLocation::new(4, 3),
+    ///     FretboardLocation::new(3, 2),
+    ///     FretboardLocation::new(2, 1),
+    ///     FretboardLocation::new(1, 1),
+    /// ];
+    /// let diagram = FretboardDiagram::new(&guitar, locations).transpose(2);
+    /// assert_eq!(
+    ///     diagram.grid_model().markers,
+    ///     vec![
+    ///         FretboardLocation::new(6, 3),
+    ///         FretboardLocation::new(5, 5),
+    ///         FretboardLocation::new(4, 5),
+    ///         FretboardLocation::new(3, 4),
+    ///         FretboardLocation::new(2, 3),
+    ///         FretboardLocation::new(1, 3),
+    ///     ]
+    /// );
+    ///
+    /// // Sliding down two frets moves the fretted notes off the neck
+    /// let diagram = FretboardDiagram::new(&guitar, vec![FretboardLocation::new(2, 1)]);
+    /// assert!(diagram.transpose(-2).grid_model().markers.is_empty());
+    /// ```
+    pub fn transpose(&self, frets: i32) -> Self {
+        let locations = self
+            .locations
+            .iter()
+            .filter_map(|loc| {
+                let fret_number = loc.fret_number as i64 + frets as i64;
+                if fret_number < 0 || fret_number > self.guitar.num_frets as i64 {
+                    return None;
+                }
+
+                Some(FretboardLocation {
+                    fret_number: fret_number as usize,
+                    ..*loc
+                })
+            })
+            .collect();
+
+        Self { locations, ..*self }
+    }
+
+    /// Sets the number that the open string is labelled with, shifting every
+    /// fret label by the same amount. By default, the open string is labelled
+    /// as fret 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let locations = vec![FretboardLocation::new(1, 0), FretboardLocation::new(2, 1)];
+    /// let diagram = FretboardDiagram::new(&guitar, locations).fret_numbering_base(1);
+    /// assert_eq!(diagram.to_string(), "-----∗ 1\n││││∗│ 2\n");
+    /// ```
+    pub fn fret_numbering_base(mut self, base: usize) -> Self {
+        self.fret_numbering_base = base;
+        self
+    }
+
+    /// Draws the diagram in landscape orientation, with the strings running
+    /// horizontally (the highest at the top) and the frets progressing from left
+    /// to right.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// let locations = vec![FretboardLocation::new(6, 3), FretboardLocation::new(5, 5)];
+    /// let lines = FretboardDiagram::new(&guitar, locations).landscape().lines();
+    /// assert_eq!(
+    ///     lines,
+    ///     vec![
+    ///         "─────────",
+    ///         "─────────",
+    ///         "─────────",
+    ///         "─────────",
+    ///         "───────∗─",
+    ///         "─∗───────",
+    ///         " 3  4  5",
+    ///     ]
+    /// );
+    ///
+    /// // Muted strings are marked to the left of the nut, if there are any
+    /// let c_major = gitar::open_chord_shape("C").unwrap();
+    /// let diagram = FretboardDiagram::new(&guitar, c_major).mute_unused_strings();
+    /// assert_eq!(diagram.landscape().lines()[5], "x ---─────────");
+    ///
+    /// let e_minor = gitar::open_chord_shape("Em").unwrap();
+    /// let diagram = FretboardDiagram::new(&guitar, e_minor).mute_unused_strings();
+    /// assert_eq!(diagram.landscape().lines()[0], "-∗-──────");
+    /// ```
+    pub fn landscape(mut self) -> Self {
+        self.landscape = true;
+        self
+    }
+}
+
+impl<'g> fmt::Display for FretboardDiagram<'g> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A structured description of a `FretboardDiagram`, describing what is drawn
+/// rather than how.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagramModel {
+    /// The number of strings drawn.
+    pub num_strings: usize,
+    /// The lowest fret drawn.
+    pub lowest_fret: usize,
+    /// The highest fret drawn.
+    pub highest_fret: usize,
+    /// Whether the open strings (and therefore the nut) are drawn.
+    pub shows_nut: bool,
+    /// The locations that are marked.
+    pub markers: Vec<FretboardLocation>,
+    /// The numbers of the strings that are marked as muted.
+    pub muted_strings: Vec<usize>,
+    /// The label of each fret drawn, from the lowest to the highest.
+    pub fret_labels: Vec<usize>,
+}
+
+/// A horizontal diagram of a `Guitar`'s whole neck, with each string drawn as
+/// a line from the nut to the final fret and the given locations marked.
+///
+/// The highest string is drawn at the top, as in guitar tablature.
+pub struct NeckDiagram<'g> {
+    guitar: &'g Guitar,
+    locations: Vec<FretboardLocation>,
+    highlighted: Vec<FretboardLocation>,
+}
+
+impl<'g> NeckDiagram<'g> {
+    /// Creates a new `NeckDiagram` based on the given `guitar` and fretboard
+    /// `locations`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::NeckDiagram;
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(5)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    /// let locations = guitar.locations(Note::from_str("E4").unwrap());
+    ///
+    /// assert_eq!(
+    ///     NeckDiagram::new(&guitar, locations).to_string(),
+    ///     "∗‖───│───│───│───│───│\n\
+    ///      -‖───│───│───│───│─∗─│\n\
+    ///      -‖───│───│───│───│───│\n\
+    ///      -‖───│───│───│───│───│\n\
+    ///      -‖───│───│───│───│───│\n\
+    ///      -‖───│───│───│───│───│\n\
+    ///      0  1   2   3   4   5\n"
+    /// );
+    /// ```
+    pub fn new(guitar: &'g Guitar, locations: Vec<FretboardLocation>) -> Self {
+        Self {
+            guitar,
+            locations,
+            highlighted: Vec::new(),
+        }
+    }
+
+    /// Marks the given `locations` with an `O` rather than a `∗`, so that they
+    /// stand out from the other locations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardLocation, NeckDiagram};
+    /// use minstrel::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let guitar = gitar::Luthier::new(3)
+    ///     .string(vec![Note::from_str("E2").unwrap()])
+    ///     .build();
+    /// let locations = vec![FretboardLocation::new(1, 0), FretboardLocation::new(1, 3)];
+    ///
+    /// let diagram = NeckDiagram::new(&guitar, locations)
+    ///     .highlight(vec![FretboardLocation::new(1, 3)]);
+    /// assert_eq!(
+    ///     diagram.to_string(),
+    ///     "∗‖───│───│─O─│\n\
+    ///      0  1   2   3\n"
+    /// );
+    /// ```
+    pub fn highlight(mut self, locations: Vec<FretboardLocation>) -> Self {
+        self.highlighted = locations;
+        self
+    }
+}
+
+impl<'g> fmt::Display for NeckDiagram<'g> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for string_num in 1..=self.guitar.strings.len() {
+            for fret_idx in 0..=self.guitar.num_frets {
+                let is_current = |loc: &FretboardLocation| {
+                    loc.string_number == string_num && loc.fret_number == fret_idx
+                };
+                let marker = if self.highlighted.iter().any(is_current) {
+                    Some('O')
+                } else if self.locations.iter().any(is_current) {
+                    Some('∗')
+                } else {
+                    None
+                };
+
+                // The open string is drawn to the left of the nut
+                match (fret_idx, marker) {
+                    (0, Some(marker)) => write!(f, "{}‖", marker)?,
+                    (0, None) => f.write_str("-‖")?,
+                    (_, Some(marker)) => write!(f, "─{}─│", marker)?,
+                    (_, None) => f.write_str("───│")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        // Labels each fret beneath the centre of its column
+        let mut fret_labels = String::from("0 ");
+        for fret_idx in 1..=self.guitar.num_frets {
+            fret_labels.push_str(&format!("{:^3} ", fret_idx));
+        }
+        writeln!(f, "{}", fret_labels.trim_end())
+    }
+}
+
+/// A location on a fretboard.
+///
+/// A `fret_number` of 0 indicates an open string.
+///
+/// Locations are ordered by fret number, and then by string number, which
+/// gives a predictable order to sorted search results. Fingering (see
+/// `with_finger()`) is ignored when comparing locations.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// let mut locations = [
+///     guitar.locations(Note::from_str("E4").unwrap()),
+///     guitar.locations(Note::from_str("A3").unwrap()),
+/// ]
+/// .concat();
+///
+/// locations.sort();
+/// assert_eq!(
+///     locations,
+///     vec![
+///         FretboardLocation::new(1, 0),
+///         FretboardLocation::new(3, 2),
+///         FretboardLocation::new(2, 5),
+///         FretboardLocation::new(4, 7),
+///         FretboardLocation::new(3, 9),
+///         FretboardLocation::new(5, 12),
+///         FretboardLocation::new(4, 14),
+///         FretboardLocation::new(6, 17),
+///         FretboardLocation::new(5, 19),
+///     ]
+/// );
+///
+/// assert_eq!(
+///     FretboardLocation::new(5, 3).with_finger(3),
+///     FretboardLocation::new(5, 3)
+/// );
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FretboardLocation {
+    string_number: usize,
+    fret_number: usize,
+    finger: Option<usize>,
+}
+
+impl FretboardLocation {
+    /// Creates a new `FretboardLocation` from the given `string_number` and
+    /// `fret_number`.
+    pub fn new(string_number: usize, fret_number: usize) -> Self {
+        Self {
+            string_number,
+            fret_number,
+            finger: None,
+        }
+    }
+
+    /// Returns the number of the string that the location is on.
+    pub fn string_number(&self) -> usize {
+        self.string_number
+    }
+
+    /// Returns the number of the fret that the location is on.
+    pub fn fret_number(&self) -> usize {
+        self.fret_number
+    }
+
+    /// Returns the finger used to fret the location, if one has been assigned.
+    pub fn finger(&self) -> Option<usize> {
+        self.finger
+    }
+
+    /// Assigns the finger (1 to 4, from index to little finger) used to fret
+    /// the location. Fingered locations are drawn as their finger number in a
+    /// `FretboardDiagram`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gitar::{FretboardDiagram, FretboardLocation};
+    ///
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    ///
+    /// // The fretted notes of an open C major chord
+    /// let locations = vec![
+    ///     FretboardLocation::new(5, 3).with_finger(3),
+    ///     FretboardLocation::new(4, 2).with_finger(2),
+    ///     FretboardLocation::new(2, 1).with_finger(1),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     FretboardDiagram::new(&guitar, locations).to_string(),
+    ///     "││││1│ 1\n\
+    ///      ││2│││ 2\n\
+    ///      │3││││ 3\n"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the given `finger` is not between 1 and 4.
+    pub fn with_finger(mut self, finger: usize) -> Self {
+        if !(1..=4).contains(&finger) {
+            panic!("finger numbers must be between 1 and 4");
+        }
+
+        self.finger = Some(finger);
+        self
+    }
+}
+
+impl PartialEq for FretboardLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FretboardLocation {}
+
+impl Ord for FretboardLocation {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.fret_number, self.string_number).cmp(&(other.fret_number, other.string_number))
+    }
+}
+
+impl PartialOrd for FretboardLocation {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for FretboardLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.string_number, self.fret_number)
+    }
+}
+
+/// Returns a compact, single-line summary of the fretboard `locations` of a
+/// `note`, listing each location as a `string-fret` pair.
+///
+/// This is an alternative to a `FretboardDiagram` that is easier to pipe into
+/// other tools.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let guitar = gitar::Luthier::new(20)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// let note = Note::from_str("E3").unwrap();
+/// assert_eq!(
+///     gitar::location_summary(note, &guitar.locations(note)),
+///     "E: 4-2, 5-7, 6-12"
+/// );
+/// ```
+pub fn location_summary(note: Note, locations: &[FretboardLocation]) -> String {
+    let locations: Vec<_> = locations.iter().map(|loc| loc.to_string()).collect();
+    format!("{}: {}", note, locations.join(", "))
+}
+
+/// Returns the lowest of the given `locations` on each string, ordered by
+/// string number. This collapses a search across octaves down to one location
+/// per string.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// // Every E, regardless of octave
+/// let locations = guitar.all_positions_pitch_class(Note::from_str("E").unwrap());
+/// assert_eq!(
+///     gitar::lowest_per_string(locations),
+///     vec![
+///         FretboardLocation::new(1, 0),
+///         FretboardLocation::new(2, 5),
+///         FretboardLocation::new(3, 9),
+///         FretboardLocation::new(4, 2),
+///         FretboardLocation::new(5, 7),
+///         FretboardLocation::new(6, 0),
+///     ]
+/// );
+/// ```
+pub fn lowest_per_string(mut locations: Vec<FretboardLocation>) -> Vec<FretboardLocation> {
+    locations.sort_by_key(|loc| (loc.string_number, loc.fret_number));
+    locations.dedup_by_key(|loc| loc.string_number);
+    locations
+}
+
+/// Returns whether the chord shape made up of the given `locations` is
+/// moveable, i.e. whether it can be slid up and down the neck because it
+/// uses no open strings.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+///
+/// // An open E major chord
+/// let open_shape = [
+///     FretboardLocation::new(6, 0),
+///     FretboardLocation::new(5, 2),
+///     FretboardLocation::new(4, 2),
+///     FretboardLocation::new(3, 1),
+///     FretboardLocation::new(2, 0),
+///     FretboardLocation::new(1, 0),
+/// ];
+/// assert!(!gitar::is_moveable_shape(&open_shape));
+///
+/// // The same chord shape, barred at the first fret (F major)
+/// let barre_shape = [
+///     FretboardLocation::new(6, 1),
+///     FretboardLocation::new(5, 3),
+///     FretboardLocation::new(4, 3),
+///     FretboardLocation::new(3, 2),
+///     FretboardLocation::new(2, 1),
+///     FretboardLocation::new(1, 1),
+/// ];
+/// assert!(gitar::is_moveable_shape(&barre_shape));
+/// ```
+pub fn is_moveable_shape(locations: &[FretboardLocation]) -> bool {
+    !locations.is_empty() && locations.iter().all(|loc| loc.fret_number > 0)
+}
+
+/// Checks that the voicing made up of the given `locations` can be played,
+/// returning an error describing the problem if two of the locations share a
+/// string.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+///
+/// let power_chord = [FretboardLocation::new(6, 5), FretboardLocation::new(5, 7)];
+/// assert!(gitar::validate_voicing(&power_chord).is_ok());
+///
+/// let conflicting = [FretboardLocation::new(5, 3), FretboardLocation::new(5, 7)];
+/// assert_eq!(
+///     gitar::validate_voicing(&conflicting),
+///     Err("string 5 is used by both 5-3 and 5-7".to_string()),
+/// );
+/// ```
+pub fn validate_voicing(locations: &[FretboardLocation]) -> Result<(), String> {
+    for (i, a) in locations.iter().enumerate() {
+        if let Some(b) = locations[i + 1..]
+            .iter()
+            .find(|b| b.string_number == a.string_number)
+        {
+            return Err(format!(
+                "string {} is used by both {} and {}",
+                a.string_number, a, b
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the interval (in semitones) between the notes at the fretboard
+/// locations `a` and `b` of the given `guitar`, or `None` if either location
+/// is not on the guitar.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+///
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// // A 'power chord' shape, which forms a perfect fifth (A2 to E3)
+/// let interval = gitar::interval_between_locations(
+///     &guitar,
+///     &FretboardLocation::new(6, 5),
+///     &FretboardLocation::new(5, 7),
+/// );
+/// assert_eq!(interval, Some(7));
+/// ```
+pub fn interval_between_locations(
+    guitar: &Guitar,
+    a: &FretboardLocation,
+    b: &FretboardLocation,
+) -> Option<usize> {
+    let a = guitar.note_at(a.string_number, a.fret_number)?;
+    let b = guitar.note_at(b.string_number, b.fret_number)?;
+    Some(b - a)
+}
+
+/// Returns the number of the string that carries the lowest `root` note (in
+/// any octave) of the chord `shape` on the given `guitar`, or `None` if the
+/// root is not part of the shape. This is the string to anchor to when
+/// sliding a moveable shape.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// // An 'E-shape' A major barre chord at the fifth fret
+/// let shape = [
+///     FretboardLocation::new(6, 5),
+///     FretboardLocation::new(5, 7),
+///     FretboardLocation::new(4, 7),
+///     FretboardLocation::new(3, 6),
+///     FretboardLocation::new(2, 5),
+///     FretboardLocation::new(1, 5),
+/// ];
+/// let root = Note::from_str("A").unwrap();
+/// assert_eq!(gitar::root_string_of_shape(&shape, &guitar, root), Some(6));
+/// ```
+pub fn root_string_of_shape(
+    shape: &[FretboardLocation],
+    guitar: &Guitar,
+    root: Note,
+) -> Option<usize> {
+    let root = root.disregard_octave();
+    shape
+        .iter()
+        .filter_map(|loc| {
+            let note = guitar.note_at(loc.string_number, loc.fret_number)?;
+            Some((loc.string_number, note))
+        })
+        .filter(|(_, note)| note.disregard_octave() == root)
+        .min_by_key(|(_, note)| note.value)
+        .map(|(string_number, _)| string_number)
+}
+
+/// Returns the common open-position shape of the chord with the given
+/// `symbol`, or `None` if there is no such shape in the library.
+///
+/// Shapes are for a guitar in standard tuning, and strings that are not part
+/// of a shape should be muted. Fretted locations include the finger that is
+/// usually used to fret them. The available chords are `C`, `A`, `G`, `E`,
+/// `D`, `Am`, `Em` and `Dm`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gitar::FretboardLocation;
+///
+/// let c_major = gitar::open_chord_shape("C").unwrap();
+/// assert_eq!(
+///     c_major,
+///     vec![
+///         FretboardLocation::new(5, 3),
+///         FretboardLocation::new(4, 2),
+///         FretboardLocation::new(3, 0),
+///         FretboardLocation::new(2, 1),
+///         FretboardLocation::new(1, 0),
+///     ]
+/// );
+/// assert_eq!(
+///     c_major.iter().map(|loc| loc.finger()).collect::<Vec<_>>(),
+///     vec![Some(3), Some(2), None, Some(1), None]
+/// );
+/// assert_eq!(gitar::open_chord_shape("F#m7b5"), None);
+/// ```
+pub fn open_chord_shape(symbol: &str) -> Option<Vec<FretboardLocation>> {
+    // Each location is given as (string number, fret number, finger), where a
+    // finger of 0 denotes an open string
+    let shape: &[(usize, usize, usize)] = match symbol {
+        "C" => &[(5, 3, 3), (4, 2, 2), (3, 0, 0), (2, 1, 1), (1, 0, 0)],
+        "A" => &[(5, 0, 0), (4, 2, 1), (3, 2, 2), (2, 2, 3), (1, 0, 0)],
+        "G" => &[
+            (6, 3, 2),
+            (5, 2, 1),
+            (4, 0, 0),
+            (3, 0, 0),
+            (2, 0, 0),
+            (1, 3, 3),
+        ],
+        "E" => &[
+            (6, 0, 0),
+            (5, 2, 2),
+            (4, 2, 3),
+            (3, 1, 1),
+            (2, 0, 0),
+            (1, 0, 0),
+        ],
+        "D" => &[(4, 0, 0), (3, 2, 1), (2, 3, 3), (1, 2, 2)],
+        "Am" => &[(5, 0, 0), (4, 2, 2), (3, 2, 3), (2, 1, 1), (1, 0, 0)],
+        "Em" => &[
+            (6, 0, 0),
+            (5, 2, 2),
+            (4, 2, 3),
+            (3, 0, 0),
+            (2, 0, 0),
+            (1, 0, 0),
+        ],
+        "Dm" => &[(4, 0, 0), (3, 2, 2), (2, 3, 3), (1, 1, 1)],
+        _ => return None,
+    };
+
+    Some(
+        shape
+            .iter()
+            .map(|&(string_number, fret_number, finger)| {
+                let loc = FretboardLocation::new(string_number, fret_number);
+                if finger == 0 {
+                    loc
+                } else {
+                    loc.with_finger(finger)
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Returns the given fretboard `locations` of the `guitar` as CSV, with a
+/// `string,fret,note` header followed by one row per location.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Note;
+/// use std::str::FromStr;
+///
+/// let guitar = gitar::Luthier::new(20)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// let locations = guitar.locations(Note::from_str("E3").unwrap());
+/// assert_eq!(
+///     gitar::locations_csv(&guitar, &locations),
+///     "string,fret,note\n4,2,E3\n5,7,E3\n6,12,E3\n"
+/// );
+/// ```
+pub fn locations_csv(guitar: &Guitar, locations: &[FretboardLocation]) -> String {
+    let mut csv = String::from("string,fret,note\n");
+    for loc in locations {
+        if let Some(note) = guitar.note_at(loc.string_number, loc.fret_number) {
+            csv.push_str(&format!(
+                "{},{},{:#}\n",
+                loc.string_number, loc.fret_number, note
+            ));
+        }
+    }
+
+    csv
+}
+
+/// Returns the voicing made up of the given `locations` in the bracketed fret
+/// notation commonly used for chords, listing the fret played on each string
+/// of the `guitar` from the lowest string to the highest (with `x` for muted
+/// strings).
+///
+/// # Examples
+///
+/// ```rust
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// let c_major = gitar::open_chord_shape("C").unwrap();
+/// assert_eq!(gitar::voicing_string(&guitar, &c_major), "[x 3 2 0 1 0]");
+/// ```
+pub fn voicing_string(guitar: &Guitar, locations: &[FretboardLocation]) -> String {
+    let frets: Vec<_> = (1..=guitar.strings.len())
+        .rev()
+        .map(
+            |string_num| match locations.iter().find(|loc| loc.string_number == string_num) {
+                Some(loc) => loc.fret_number.to_string(),
+                None => String::from("x"),
+            },
+        )
+        .collect();
+
+    format!("[{}]", frets.join(" "))
+}
+
+/// Returns the voicing made up of the given `locations` as a single column of
+/// guitar tablature, with one line per string of the `guitar` from the highest
+/// string to the lowest, labelled with its open note (and with `x` for muted
+/// strings).
+///
+/// # Examples
+///
+/// ```rust
+/// let guitar = gitar::Luthier::new(21)
+///     .string(gitar::standard_tuning())
+///     .build();
+///
+/// let g_major = gitar::open_chord_shape("G").unwrap();
+/// assert_eq!(
+///     gitar::voicing_tab(&guitar, &g_major),
+///     "E|-3-\n\
+///      B|-0-\n\
+///      G|-0-\n\
+///      D|-0-\n\
+///      A|-2-\n\
+///      E|-3-\n"
+/// );
+///
+/// let c_major = gitar::open_chord_shape("C").unwrap();
+/// assert!(gitar::voicing_tab(&guitar, &c_major).ends_with("E|-x-\n"));
+/// ```
+pub fn voicing_tab(guitar: &Guitar, locations: &[FretboardLocation]) -> String {
+    let rows: Vec<_> = guitar
+        .strings
+        .iter()
+        .enumerate()
+        .map(|(string_idx, string)| {
+            let fret = match locations
+                .iter()
+                .find(|loc| loc.string_number == string_idx + 1)
+            {
+                Some(loc) => loc.fret_number.to_string(),
+                None => String::from("x"),
+            };
+            (string.frets[0].to_string(), fret)
+        })
+        .collect();
+
+    // Pads the columns so that they line up, even with accidentals and
+    // two-digit fret numbers
+    let name_width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    let fret_width = rows.iter().map(|(_, fret)| fret.len()).max().unwrap_or(0);
+
+    let mut tab = String::new();
+    for (name, fret) in rows {
+        tab.push_str(&format!(
+            "{:<name_width$}|-{:-<fret_width$}-\n",
+            name,
+            fret,
+            name_width = name_width,
+            fret_width = fret_width
+        ));
+    }
+
+    tab
 }
@@ -1,12 +1,14 @@
-use crate::{Guitar, GuitarString};
+use crate::{nearest_note, standard_tuning, Guitar, GuitarString};
 use minstrel::Note;
 
 /// A `Guitar` builder.
 #[derive(Debug)]
 pub struct Luthier {
     num_frets: usize,
+    capo: usize,
     tuning: Vec<Note>,
     strings: Vec<GuitarString>,
+    used_default_tuning: bool,
 }
 
 impl Luthier {
@@ -21,13 +23,16 @@ impl Luthier {
     pub fn new(num_frets: usize) -> Self {
         Self {
             num_frets,
+            capo: 0,
             tuning: Vec::new(),
             strings: Vec::new(),
+            used_default_tuning: false,
         }
     }
 
     /// Strings the luthier's `Guitar` with the given `tuning` (a vector
-    /// of open string note values).
+    /// of open string note values, ordered from the lowest string to the
+    /// highest).
     ///
     /// # Examples
     ///
@@ -53,9 +58,101 @@ impl Luthier {
             .rev()
             .map(|open_note| GuitarString::new(*open_note, self.num_frets))
             .collect();
+        self.used_default_tuning = false;
         self
     }
 
+    /// Strings the luthier's `Guitar` with the notes nearest to the given open
+    /// string frequencies (in hertz), ordered from the lowest string to the
+    /// highest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let guitar = gitar::Luthier::new(21)
+    ///     .string_from_frequencies(&[82.41, 110.0, 146.83, 196.0, 246.94, 329.63])
+    ///     .build();
+    ///
+    /// let open_notes: Vec<_> = guitar.open_notes().collect();
+    /// assert_eq!(open_notes, gitar::standard_tuning());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the given frequencies is not a finite,
+    /// positive number, or is below the lowest representable note (C0, at
+    /// roughly 16.35 Hz).
+    pub fn string_from_frequencies(self, open_freqs: &[f64]) -> Self {
+        let tuning = open_freqs
+            .iter()
+            .map(|&freq| {
+                if !freq.is_finite() || freq <= 0.0 {
+                    panic!("the open string frequency was not a finite, positive number");
+                }
+
+                nearest_note(freq).expect("the open string frequency was lower than C0")
+            })
+            .collect();
+        self.string(tuning)
+    }
+
+    /// Strings the luthier's `Guitar` with the given `tuning`, ordered from
+    /// the highest string to the lowest (i.e. by string number).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut high_to_low = gitar::standard_tuning();
+    /// high_to_low.reverse();
+    ///
+    /// let guitar = gitar::Luthier::new(21).string_high_to_low(high_to_low).build();
+    /// let expected = gitar::Luthier::new(21)
+    ///     .string(gitar::standard_tuning())
+    ///     .build();
+    /// assert_eq!(guitar.fretboard_grid(), expected.fretboard_grid());
+    /// ```
+    pub fn string_high_to_low(self, mut tuning: Vec<Note>) -> Self {
+        tuning.reverse();
+        self.string(tuning)
+    }
+
+    /// Strings the luthier's `Guitar` with the given `tuning`, or with
+    /// `standard_tuning()` if the given `tuning` is empty. Whether the
+    /// fallback was used can be checked with `used_default_tuning()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let luthier = gitar::Luthier::new(21).string_or_standard(Vec::new());
+    /// assert!(luthier.used_default_tuning());
+    ///
+    /// let open_notes: Vec<_> = luthier.build().open_notes().collect();
+    /// assert_eq!(open_notes, gitar::standard_tuning());
+    /// ```
+    pub fn string_or_standard(self, tuning: Vec<Note>) -> Self {
+        if tuning.is_empty() {
+            let mut luthier = self.string(standard_tuning());
+            luthier.used_default_tuning = true;
+            luthier
+        } else {
+            self.string(tuning)
+        }
+    }
+
+    /// Returns whether the luthier's `Guitar` was strung with
+    /// `standard_tuning()` by `string_or_standard()` because the given tuning
+    /// was empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let luthier = gitar::Luthier::new(21).string_or_standard(gitar::standard_tuning());
+    /// assert!(!luthier.used_default_tuning());
+    /// ```
+    pub fn used_default_tuning(&self) -> bool {
+        self.used_default_tuning
+    }
+
     /// Puts a 'capo' on the luthier's `Guitar`. This essentially shifts the
     /// fret values of each string up by `fret_number` of semitones. It also
     /// reduces the number of frets on the guitar by the same amount.
@@ -85,6 +182,7 @@ impl Luthier {
         }
 
         self.num_frets -= fret_number;
+        self.capo = fret_number;
         self.strings = self
             .tuning
             .iter()
@@ -98,6 +196,7 @@ impl Luthier {
     pub fn build(self) -> Guitar {
         Guitar {
             num_frets: self.num_frets,
+            capo: self.capo,
             strings: self.strings,
         }
     }
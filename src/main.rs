@@ -6,17 +6,33 @@ use structopt::StructOpt;
 enum Opt {
     /// Finds the occurences of the given note on a guitar.
     Find {
+        #[structopt(parse(try_from_str = gitar::parse_note))]
         note: Note,
         /// The number of frets on the guitar.
         #[structopt(short = "f", long = "frets", default_value = "21")]
         num_frets: usize,
         /// A tuning configuration for the guitar.
-        #[structopt(short = "t", long = "tuning")]
+        #[structopt(short = "t", long = "tuning", parse(try_from_str = gitar::parse_note))]
         tuning: Option<Vec<Note>>,
         /// The fret number of a capo.
         #[structopt(short = "c", long = "capo")]
         capo: Option<usize>,
+        /// The highest fret to search for occurences on.
+        #[structopt(short = "m", long = "max-fret")]
+        max_fret: Option<usize>,
+        /// Prints the occurences as CSV instead of as a diagram.
+        #[structopt(long = "csv")]
+        csv: bool,
+        /// Lists the note name of each occurence beneath the diagram.
+        #[structopt(short = "n", long = "names")]
+        names: bool,
+        /// Draws the diagram with the strings running horizontally.
+        #[structopt(short = "l", long = "landscape")]
+        landscape: bool,
     },
+    /// Finds the note nearest to the given frequency (in hertz), and how far
+    /// the frequency is from it in cents.
+    Tune { freq: f64 },
 }
 
 fn main() -> anyhow::Result<()> {
@@ -27,17 +43,28 @@ fn main() -> anyhow::Result<()> {
             num_frets,
             tuning,
             capo,
+            max_fret,
+            names,
+            csv,
+            landscape,
         } => {
-            // Uses standard tuning if there was no given tuning (or if the given
-            // tuning was invalid)
-            let tuning = tuning.unwrap_or_else(gitar::standard_tuning);
-
             let capo = capo.unwrap_or(0);
 
-            let luthier = Luthier::new(num_frets).string(tuning).add_capo(capo);
+            // Uses standard tuning if there was no given tuning
+            let luthier = Luthier::new(num_frets)
+                .string_or_standard(tuning.unwrap_or_default())
+                .add_capo(capo);
             let guitar = luthier.build();
 
-            let locations = guitar.locations(note);
+            let locations = match max_fret {
+                Some(max_fret) => guitar.locations_in_range(note, 0, max_fret),
+                None => guitar.locations(note),
+            };
+            if csv {
+                print!("{}", gitar::locations_csv(&guitar, &locations));
+                return Ok(());
+            }
+
             match locations.len() {
                 0 => {
                     println!("No occurences.");
@@ -47,8 +74,35 @@ fn main() -> anyhow::Result<()> {
                 n => println!("{} occurences:", n),
             }
 
-            println!("{}", FretboardDiagram::new(&guitar, locations));
+            let mut diagram = FretboardDiagram::new(&guitar, locations.clone());
+            if landscape {
+                diagram = diagram.landscape();
+            }
+
+            if names {
+                print!("{}", diagram);
+                for loc in locations {
+                    println!(
+                        "{:#}: string {}, fret {}",
+                        note,
+                        loc.string_number(),
+                        loc.fret_number()
+                    );
+                }
+            } else {
+                println!("{}", diagram);
+            }
         }
+        Opt::Tune { freq } if !freq.is_finite() || freq <= 0.0 => {
+            println!("{} is not a valid frequency.", freq);
+        }
+        Opt::Tune { freq } => match gitar::nearest_note(freq) {
+            Some(note) => {
+                let cents = 1200.0 * (freq / gitar::frequency(note)).log2();
+                println!("{:#} ({:+.1} cents)", note, cents);
+            }
+            None => println!("{} Hz is below the lowest note (C0).", freq),
+        },
     }
 
     Ok(())
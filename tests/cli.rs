@@ -0,0 +1,77 @@
+use std::process::Command;
+
+/// Runs `gitar` with the given arguments, returning its standard output.
+fn gitar(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_gitar"))
+        .args(args)
+        .output()
+        .expect("failed to run gitar");
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).expect("gitar printed invalid UTF-8")
+}
+
+#[test]
+fn find_lists_note_names() {
+    let output = gitar(&["find", "E3", "--names"]);
+    let lines: Vec<_> = output.lines().collect();
+
+    assert_eq!(
+        lines[lines.len() - 3..],
+        [
+            "E3: string 4, fret 2",
+            "E3: string 5, fret 7",
+            "E3: string 6, fret 12",
+        ]
+    );
+}
+
+#[test]
+fn find_excludes_frets_above_max_fret() {
+    // E3 is also on the fifth string's seventh fret and the sixth string's
+    // twelfth fret
+    assert_eq!(
+        gitar(&["find", "E3", "--max-fret", "6"]),
+        "1 occurence:\n││∗│││ 2\n\n"
+    );
+}
+
+#[test]
+fn find_prints_csv() {
+    assert_eq!(
+        gitar(&["find", "E3", "--csv"]),
+        "string,fret,note\n4,2,E3\n5,7,E3\n6,12,E3\n"
+    );
+}
+
+#[test]
+fn find_draws_landscape_diagrams() {
+    let output = gitar(&["find", "E4", "--landscape", "--max-fret", "5"]);
+    let lines: Vec<_> = output.lines().collect();
+
+    // The first string is drawn at the top, and each fret takes up three
+    // columns
+    assert_eq!(lines[1], "-∗-───────────────");
+    assert_eq!(lines[2], "---─────────────∗─");
+    assert_eq!(lines[7], " 0  1  2  3  4  5");
+}
+
+#[test]
+fn tune_finds_nearest_note() {
+    assert_eq!(gitar(&["tune", "440"]), "A4 (+0.0 cents)\n");
+}
+
+#[test]
+fn tune_rejects_frequencies_below_c0() {
+    assert_eq!(
+        gitar(&["tune", "10"]),
+        "10 Hz is below the lowest note (C0).\n"
+    );
+}
+
+#[test]
+fn tune_rejects_invalid_frequencies() {
+    assert_eq!(gitar(&["tune", "inf"]), "inf is not a valid frequency.\n");
+    assert_eq!(gitar(&["tune", "NaN"]), "NaN is not a valid frequency.\n");
+    assert_eq!(gitar(&["tune", "0"]), "0 is not a valid frequency.\n");
+}